@@ -1,61 +1,684 @@
-use aoc_rs::util;
-use clap::{App, Arg};
-use std::io::{self, BufRead};
-use std::ops::Sub;
-use std::time::Instant;
+use anyhow::{bail, Context, Result};
+use aoc_rs::util::answer::Answer;
+use aoc_rs::util::bench::{Baseline, StageStats, Stats};
+use aoc_rs::util::puzzles::{self, file_name};
+use aoc_rs::util::registry::Registry;
+use aoc_rs::util::runner::{self, Check};
+use aoc_rs::util::solution::Parts;
+use aoc_rs::util::status;
+use aoc_rs::util::trace;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use serde_json::{json, Value};
+use std::io::{self, BufRead, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-fn main() {
-    println!("Welcome to janreggie/aoc-rs");
+/// How answers are printed
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Human-readable text
+    Plain,
+    /// A JSON object per puzzle
+    Json,
+}
+
+impl Format {
+    fn new(matches: &ArgMatches) -> Format {
+        match matches.value_of("format") {
+            Some("json") => Format::Json,
+            _ => Format::Plain,
+        }
+    }
+}
 
+fn main() -> Result<()> {
     // Read flags
     let matches = App::new("aoc-rs")
         .author("Jan Reggie Dela Cruz")
         .about("Advent of Code in Rust")
         .arg(
-            Arg::with_name("year")
-                .short("y")
-                .long("year")
+            Arg::with_name("format")
+                .long("format")
+                .takes_value(true)
+                .value_name("fmt")
+                .possible_values(&["plain", "json"])
+                .default_value("plain")
+                .global(true)
+                .help("How to print the answers"),
+        )
+        .arg(
+            Arg::with_name("verbose")
+                .short("v")
+                .long("verbose")
+                .multiple(true)
+                .global(true)
+                .help("Write what solvers are doing to stderr; repeat for more detail (see also AOC_TRACE)"),
+        )
+        .args(&puzzle_args())
+        .arg(
+            Arg::with_name("check")
+                .long("check")
+                .help("Compare the answers against <outputs-dir>/yNN-dNN.txt"),
+        )
+        .arg(
+            Arg::with_name("expected")
+                .long("expected")
                 .takes_value(true)
-                .value_name("yr")
-                .help("Year to use (e.g., `21`)"),
+                .value_name("path")
+                .help("Compare the answers against this file instead"),
         )
         .arg(
-            Arg::with_name("day")
-                .short("d")
-                .long("day")
+            Arg::with_name("outputs-dir")
+                .long("outputs-dir")
                 .takes_value(true)
-                .value_name("d")
-                .help("Day to use (e.g., `5`)"),
+                .value_name("dir")
+                .default_value("tests/outputs")
+                .help("Folder to look for yNN-dNN.txt when using --check"),
+        )
+        .subcommand(
+            SubCommand::with_name("list")
+                .about("Lists implemented puzzles and their test data")
+                .arg(
+                    Arg::with_name("tests-dir")
+                        .long("tests-dir")
+                        .takes_value(true)
+                        .value_name("dir")
+                        .default_value("tests")
+                        .help("Folder containing inputs/, outputs/ and examples_yNN.rs"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("run")
+                .about("Runs implemented puzzles against their inputs")
+                .arg(
+                    Arg::with_name("all")
+                        .long("all")
+                        .help("Run every implemented puzzle"),
+                )
+                .arg(
+                    Arg::with_name("year")
+                        .short("y")
+                        .long("year")
+                        .takes_value(true)
+                        .value_name("yr")
+                        .help("Only run puzzles of this year (e.g., `21`)"),
+                )
+                .arg(
+                    Arg::with_name("inputs-dir")
+                        .long("inputs-dir")
+                        .takes_value(true)
+                        .value_name("dir")
+                        .default_value("tests/inputs")
+                        .help("Folder containing inputs named yNN-dNN.txt"),
+                )
+                .arg(
+                    Arg::with_name("outputs-dir")
+                        .long("outputs-dir")
+                        .takes_value(true)
+                        .value_name("dir")
+                        .default_value("tests/outputs")
+                        .help("Folder containing answers named yNN-dNN.txt"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("bench")
+                .about("Times a puzzle over many runs")
+                .args(&puzzle_args())
+                .arg(
+                    Arg::with_name("iterations")
+                        .short("n")
+                        .long("iterations")
+                        .takes_value(true)
+                        .value_name("n")
+                        .default_value("10")
+                        .help("Number of timed runs"),
+                )
+                .arg(
+                    Arg::with_name("warmup")
+                        .long("warmup")
+                        .takes_value(true)
+                        .value_name("n")
+                        .default_value("3")
+                        .help("Number of untimed runs before the timed ones"),
+                )
+                .arg(
+                    Arg::with_name("baseline")
+                        .long("baseline")
+                        .takes_value(true)
+                        .value_name("path")
+                        .help("JSON file of previous timings to compare against"),
+                )
+                .arg(
+                    Arg::with_name("save-baseline")
+                        .long("save-baseline")
+                        .requires("baseline")
+                        .help("Save the timings into the --baseline file"),
+                )
+                .arg(
+                    Arg::with_name("threshold")
+                        .long("threshold")
+                        .takes_value(true)
+                        .value_name("ratio")
+                        .default_value("0.1")
+                        .help("How much slower than the baseline is a regression"),
+                ),
         )
         .get_matches();
+
+    let global_matches = matches.subcommand().1.unwrap_or(&matches);
+    trace::init(global_matches.occurrences_of("verbose"))?;
+    let format = Format::new(global_matches);
+    if format == Format::Plain {
+        println!("Welcome to janreggie/aoc-rs");
+    }
+
+    match matches.subcommand() {
+        ("list", Some(matches)) => list(matches),
+        ("run", Some(matches)) => run(matches),
+        ("bench", Some(matches)) => bench(matches),
+        _ => solve(&matches),
+    }
+}
+
+/// Arguments for picking a puzzle and its input
+fn puzzle_args() -> Vec<Arg<'static, 'static>> {
+    vec![
+        Arg::with_name("year")
+            .short("y")
+            .long("year")
+            .takes_value(true)
+            .value_name("yr")
+            .help("Year to use (e.g., `21`)"),
+        Arg::with_name("day")
+            .short("d")
+            .long("day")
+            .takes_value(true)
+            .value_name("d")
+            .help("Day to use (e.g., `5`)"),
+        Arg::with_name("input")
+            .short("i")
+            .long("input")
+            .takes_value(true)
+            .value_name("path")
+            .help("File to read the input from, instead of stdin"),
+        Arg::with_name("inputs-dir")
+            .long("inputs-dir")
+            .takes_value(true)
+            .value_name("dir")
+            .default_value("tests/inputs")
+            .help("Folder to look for yNN-dNN.txt if stdin is a terminal"),
+        Arg::with_name("part")
+            .short("p")
+            .long("part")
+            .takes_value(true)
+            .value_name("n")
+            .possible_values(&["1", "2"])
+            .help("Only solve this part"),
+    ]
+}
+
+fn parts(matches: &ArgMatches) -> Parts {
+    match matches.value_of("part") {
+        Some("1") => Parts::One,
+        Some("2") => Parts::Two,
+        _ => Parts::Both,
+    }
+}
+
+fn year_and_day(matches: &ArgMatches) -> Result<(u8, u8)> {
     let year: u8 = matches
         .value_of("year")
-        .expect("year is required")
+        .context("year is required")?
         .parse()
-        .expect("year must be numeric");
+        .context("year must be numeric")?;
     let day: u8 = matches
         .value_of("day")
-        .expect("day is required")
+        .context("day is required")?
         .parse()
-        .expect("day must be numeric");
-    let solver = util::solve(year)(day);
+        .context("day must be numeric")?;
+    Ok((year, day))
+}
 
-    // Read stdin
-    let lines = read_lines();
-    let lines = lines.expect("error in reading stdin");
+/// Reads the input from `--input`, or stdin if it is piped,
+/// or `<inputs-dir>/yNN-dNN.txt` otherwise.
+fn read_input(matches: &ArgMatches, year: u8, day: u8) -> Result<Vec<String>> {
+    if let Some(path) = matches.value_of("input") {
+        runner::read_input_file(Path::new(path))
+    } else if io::stdin().is_terminal() {
+        let inputs_dir = Path::new(matches.value_of("inputs-dir").unwrap());
+        runner::read_input(inputs_dir, year, day).context(
+            "no --input given and stdin is a terminal, so looked in --inputs-dir",
+        )
+    } else {
+        read_lines().context("error in reading stdin")
+    }
+}
+
+/// Solves a single puzzle
+fn solve(matches: &ArgMatches) -> Result<()> {
+    let (year, day) = year_and_day(matches)?;
+    let solver = Registry::new().lookup(year, day)?;
+    let lines = read_input(matches, year, day)?;
 
     // Do the needful
-    let before = Instant::now();
-    let result = match solver(lines) {
-        Err(e) => panic!("could not solve y{}-d{}: {:?}", year, day, e),
-        Ok(r) => r,
+    let format = Format::new(matches);
+    let run = runner::run(solver, year, day, lines, parts(matches))?;
+
+    // Compare against the expected answers, if asked to
+    let expected_file = match matches.value_of("expected") {
+        Some(path) => Some(PathBuf::from(path)),
+        None if matches.is_present("check") => {
+            let outputs_dir =
+                Path::new(matches.value_of("outputs-dir").unwrap());
+            Some(outputs_dir.join(file_name(year, day)))
+        }
+        None => None,
+    };
+    let expected = match &expected_file {
+        Some(expected_file) => Some(puzzles::read_answers(expected_file)?),
+        None => None,
     };
-    let after = Instant::now();
 
     // Print out the results
-    println!("Answer for Part 1 is {:?}", result.0);
-    println!("Answer for Part 2 is {:?}", result.1);
-    println!("It took {:?} to solve the current problem", after.sub(before));
+    let mut mismatches = 0;
+    match format {
+        Format::Plain => {
+            for (part, answer) in [(1, &run.answer_a), (2, &run.answer_b)] {
+                match answer {
+                    None => {}
+                    Some(Ok(answer @ Answer::Grid { text, .. })) => {
+                        match text {
+                            Some(text) => println!(
+                                "Answer for Part {} is {}, drawn as",
+                                part, text
+                            ),
+                            None => {
+                                println!("Answer for Part {} is drawn as", part)
+                            }
+                        }
+                        println!("{}", answer.draw());
+                    }
+                    Some(Ok(answer)) => {
+                        println!("Answer for Part {} is {}", part, answer)
+                    }
+                    Some(Err(e)) => {
+                        println!("Could not solve Part {}: {:#}", part, e)
+                    }
+                }
+            }
+            println!("It took {:?} to solve the current problem", run.elapsed);
+            let stages = [
+                ("parsing", run.timings.parse),
+                ("Part 1", run.timings.part1),
+                ("Part 2", run.timings.part2),
+            ];
+            for (stage, time) in stages {
+                if let Some(time) = time {
+                    println!("  {} took {:?}", stage, time);
+                }
+            }
+        }
+        Format::Json => {
+            let mut json = run.to_json();
+            if let Some((expected_a, expected_b)) = &expected {
+                json["part1"]["check"] =
+                    Check::new(expected_a, &run.answer_a).name().into();
+                json["part2"]["check"] =
+                    Check::new(expected_b, &run.answer_b).name().into();
+            }
+            println!("{}", json);
+        }
+    }
+    if let Some((expected_a, expected_b)) = &expected {
+        for (part, expected, actual) in
+            [(1, expected_a, &run.answer_a), (2, expected_b, &run.answer_b)]
+        {
+            let check = Check::new(expected, actual);
+            if check == Check::Fail {
+                mismatches += 1;
+            }
+            // Parts that were not asked for are not reported
+            if format == Format::Json || actual.is_none() {
+                continue;
+            }
+            match check {
+                Check::Pass => println!("Part {} matches", part),
+                Check::Unchecked => {
+                    println!("Part {} has no expected answer", part)
+                }
+                Check::Fail => {
+                    println!("Part {} does not match", part);
+                    print_diff(expected.as_deref().unwrap_or_default(), actual);
+                }
+            }
+        }
+    }
+    if mismatches > 0 {
+        bail!(
+            "{} answers did not match {}",
+            mismatches,
+            expected_file.unwrap().display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints expected lines prefixed with `-`, and actual lines prefixed with `+`
+fn print_diff(expected: &str, actual: &Option<Result<Answer>>) {
+    for line in expected.lines() {
+        println!("- {}", line);
+    }
+    match actual {
+        None => {}
+        Some(Ok(actual)) => {
+            for line in actual.to_string().lines() {
+                println!("+ {}", line);
+            }
+        }
+        Some(Err(e)) => println!("+ (error: {:#})", e),
+    }
+}
+
+/// Prints a day x year matrix of implemented puzzles
+fn list(matches: &ArgMatches) -> Result<()> {
+    let registry = Registry::new();
+    let tests_dir = Path::new(matches.value_of("tests-dir").unwrap());
+    let statuses = status::collect(&registry, tests_dir);
+    let years = registry.years();
+
+    print!("day");
+    for year in &years {
+        print!("  y{:02}", year);
+    }
+    println!();
+    for day in 1..=25 {
+        print!("{:>3}", day);
+        for year in &years {
+            let status = statuses
+                .iter()
+                .find(|s| s.year == *year && s.day == day)
+                .context("missing status")?;
+            let flag = |b: bool, c: char| if b { c } else { '-' };
+            print!(
+                " {}{}{}{}",
+                flag(status.implemented, 'S'),
+                flag(status.has_input, 'I'),
+                flag(status.has_output, 'O'),
+                flag(status.has_examples, 'E'),
+            );
+        }
+        println!();
+    }
+    println!();
+    println!("S: solver implemented");
+    println!("I: input in {}", tests_dir.join("inputs").display());
+    println!("O: answers in {}", tests_dir.join("outputs").display());
+    println!("E: examples in {}", tests_dir.join("examples_yNN.rs").display());
+
+    Ok(())
+}
+
+/// Runs many puzzles and prints a summary table
+fn run(matches: &ArgMatches) -> Result<()> {
+    let registry = Registry::new();
+    let year: Option<u8> = match matches.value_of("year") {
+        Some(year) => Some(year.parse().context("year must be numeric")?),
+        None => None,
+    };
+    if year.is_none() && !matches.is_present("all") {
+        bail!("either --all or --year is required");
+    }
+    let inputs_dir = Path::new(matches.value_of("inputs-dir").unwrap());
+    let outputs_dir = Path::new(matches.value_of("outputs-dir").unwrap());
+
+    let format = Format::new(matches);
+
+    let (mut passed, mut failed, mut unchecked) = (0, 0, 0);
+    let mut total_time = Duration::ZERO;
+    let mut runs = Vec::new();
+    if format == Format::Plain {
+        println!(
+            "{:<8} {:<20} {:<20} {:<5} {:<5} time",
+            "puzzle", "part 1", "part 2", "1", "2"
+        );
+    }
+    for (yr, day) in registry.list() {
+        if year.is_some_and(|year| year != yr) {
+            continue;
+        }
+        let name = format!("y{:02}-d{:02}", yr, day);
+        // Only a missing input is skipped; one that cannot be read is a failure
+        let input_path = inputs_dir.join(file_name(yr, day));
+        if !input_path.is_file() {
+            match format {
+                Format::Plain => println!("{:<8} no input", name),
+                Format::Json => runs.push(json!({
+                    "year": yr,
+                    "day": day,
+                    "skipped": "no input",
+                })),
+            }
+            continue;
+        }
+        let solver = registry.lookup(yr, day)?;
+        // A bad output file only fails its own puzzle, not the whole batch
+        let run =
+            runner::read_expected(outputs_dir, yr, day).and_then(|expected| {
+                let lines = runner::read_input_file(&input_path)?;
+                let run = runner::run(solver, yr, day, lines, Parts::Both)?;
+                Ok((expected, run))
+            });
+        let ((expected_a, expected_b), run) = match run {
+            Ok(run) => run,
+            Err(e) => {
+                match format {
+                    Format::Plain => println!("{:<8} {:#}", name, e),
+                    Format::Json => runs.push(json!({
+                        "year": yr,
+                        "day": day,
+                        "error": format!("{:#}", e),
+                    })),
+                }
+                failed += 2;
+                continue;
+            }
+        };
+        total_time += run.elapsed;
+
+        let checks = [
+            Check::new(&expected_a, &run.answer_a),
+            Check::new(&expected_b, &run.answer_b),
+        ];
+        for check in checks {
+            match check {
+                Check::Pass => passed += 1,
+                Check::Fail => failed += 1,
+                Check::Unchecked => unchecked += 1,
+            }
+        }
+        match format {
+            Format::Plain => println!(
+                "{:<8} {:<20} {:<20} {:<5} {:<5} {:?}",
+                name,
+                summarize(&run.answer_a),
+                summarize(&run.answer_b),
+                describe(checks[0]),
+                describe(checks[1]),
+                run.elapsed,
+            ),
+            Format::Json => {
+                let mut json = run.to_json();
+                json["part1"]["check"] = checks[0].name().into();
+                json["part2"]["check"] = checks[1].name().into();
+                runs.push(json);
+            }
+        }
+    }
+    match format {
+        Format::Plain => {
+            println!();
+            println!(
+                "{} passed, {} failed, {} unchecked in {:?}",
+                passed, failed, unchecked, total_time
+            );
+        }
+        Format::Json => println!("{}", Value::Array(runs)),
+    }
+
+    if failed > 0 {
+        bail!("{} answers did not match", failed);
+    }
+    Ok(())
+}
+
+/// Fits an answer in a single table cell
+fn summarize(answer: &Option<Result<Answer>>) -> String {
+    let answer = match answer {
+        None => return "-".to_string(),
+        Some(Err(_)) => return "(error)".to_string(),
+        Some(Ok(answer)) => answer.to_string(),
+    };
+    if answer.contains('\n') {
+        format!("({} lines)", answer.lines().count())
+    } else if answer.chars().count() > 20 {
+        answer.chars().take(19).chain(Some('…')).collect()
+    } else {
+        answer
+    }
+}
+
+fn describe(check: Check) -> &'static str {
+    match check {
+        Check::Pass => "pass",
+        Check::Fail => "FAIL",
+        Check::Unchecked => "?",
+    }
+}
+
+/// Times a single puzzle over many runs,
+/// and compares the timings against a baseline if given
+fn bench(matches: &ArgMatches) -> Result<()> {
+    let (year, day) = year_and_day(matches)?;
+    let solver = Registry::new().lookup(year, day)?;
+    let lines = read_input(matches, year, day)?;
+    let iterations: usize = matches
+        .value_of("iterations")
+        .unwrap()
+        .parse()
+        .context("iterations must be numeric")?;
+    if iterations == 0 {
+        bail!("iterations must be at least 1");
+    }
+    let warmup: usize = matches
+        .value_of("warmup")
+        .unwrap()
+        .parse()
+        .context("warmup must be numeric")?;
+    let threshold: f64 = matches
+        .value_of("threshold")
+        .unwrap()
+        .parse()
+        .context("threshold must be numeric")?;
+    let format = Format::new(matches);
+
+    let parts = parts(matches);
+
+    for _ in 0..warmup {
+        runner::run(solver, year, day, lines.clone(), parts)?;
+    }
+    // Staged solvers are timed per stage, and the rest as a whole
+    let (mut solve, mut parse, mut part1, mut part2) =
+        (vec![], vec![], vec![], vec![]);
+    for _ in 0..iterations {
+        let run = runner::run(solver, year, day, lines.clone(), parts)?;
+        solve.push(run.elapsed);
+        parse.extend(run.timings.parse);
+        part1.extend(run.timings.part1);
+        part2.extend(run.timings.part2);
+    }
+    let samples = if solver.is_staged() {
+        vec![("parse", parse), ("part1", part1), ("part2", part2)]
+    } else {
+        vec![("solve", solve)]
+    };
+    let mut stages = StageStats::new();
+    for (stage, samples) in samples {
+        if let Some(stats) = Stats::new(&samples) {
+            stages.insert(stage.to_string(), stats);
+        }
+    }
+
+    // Compare against the baseline
+    let baseline_file = matches.value_of("baseline").map(Path::new);
+    let mut baseline = match baseline_file {
+        Some(path) => Baseline::read(path)?,
+        None => Baseline::default(),
+    };
+    let previous = baseline.get(year, day).cloned().unwrap_or_default();
+    let regressions = stages
+        .iter()
+        .filter(|(stage, stats)| match previous.get(*stage) {
+            Some(previous) => stats.is_regression(previous, threshold),
+            None => false,
+        })
+        .map(|(stage, _)| stage.clone())
+        .collect::<Vec<_>>();
+
+    match format {
+        Format::Plain => {
+            println!(
+                "Benchmarked y{:02}-d{:02} over {} runs after {} warm-up runs",
+                year, day, iterations, warmup
+            );
+            println!(
+                "{:<8} {:>12} {:>12} {:>12} {:>12} {:>12}",
+                "stage", "min", "median", "mean", "p95", "baseline"
+            );
+            for (stage, stats) in &stages {
+                let previous = match previous.get(stage) {
+                    Some(previous) => format!("{:.2?}", previous.median),
+                    None => "-".to_string(),
+                };
+                let row = format!(
+                    "{:<8} {:>12.2?} {:>12.2?} {:>12.2?} {:>12.2?} {:>12} {}",
+                    stage,
+                    stats.min,
+                    stats.median,
+                    stats.mean,
+                    stats.p95,
+                    previous,
+                    if regressions.contains(stage) { "REGRESSION" } else { "" },
+                );
+                println!("{}", row.trim_end());
+            }
+        }
+        Format::Json => {
+            let stages = stages
+                .iter()
+                .map(|(stage, stats)| (stage.clone(), stats.to_json()))
+                .collect::<serde_json::Map<_, _>>();
+            println!(
+                "{}",
+                json!({
+                    "year": year,
+                    "day": day,
+                    "iterations": iterations,
+                    "stages": stages,
+                    "regressions": regressions,
+                })
+            );
+        }
+    }
+
+    if matches.is_present("save-baseline") {
+        baseline.insert(year, day, stages);
+        baseline.write(baseline_file.unwrap())?;
+    }
+    if !regressions.is_empty() {
+        bail!("median time regressed by more than {}", threshold);
+    }
+    Ok(())
 }
 
 fn read_lines() -> io::Result<Vec<String>> {
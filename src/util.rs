@@ -1,25 +1,75 @@
-use crate::y17;
-use crate::y21;
-use crate::y22;
-use crate::y23;
 use anyhow::Result;
 
+use answer::Answer;
+use solution::{Output, Parts, Solution, Timings};
+
+pub mod answer;
+pub mod bench;
+pub mod geom;
+pub mod grid;
+pub mod hex;
+pub mod interval;
+pub mod ocr;
 pub mod puzzles;
+pub mod registry;
+pub mod runner;
+pub mod search;
+pub mod solution;
+pub mod status;
+pub mod trace;
 pub mod vectors;
 
-/// Solver
-pub fn solve(
-    yr: u8,
-) -> fn(u8) -> fn(Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    match yr {
-        17 => y17::solver,
-        21 => y21::solver,
-        22 => y22::solver,
-        23 => y23::solver,
-        _ => todo!("year {}", yr),
+/// Answers for Part 1 and Part 2, as returned by Whole solvers
+pub type Answers = (Result<String>, Result<String>);
+
+/// A solver takes in the lines of a puzzle input,
+/// and returns the answers for Part 1 and Part 2.
+#[derive(Clone, Copy)]
+pub enum Solver {
+    /// Parses and solves both parts in one go.
+    /// This is how most of the solvers are written.
+    Whole(fn(Vec<String>) -> Result<Answers>),
+    /// Exposes parsing, Part 1 and Part 2 separately through a Solution.
+    Staged(fn(Vec<String>, Parts) -> Result<Output>),
+}
+
+impl Solver {
+    pub const fn staged<S: Solution>() -> Solver {
+        Solver::Staged(solution::run_staged::<S>)
+    }
+
+    /// Solves the parts asked for.
+    /// A Whole solver still solves both parts, but only returns those asked for.
+    pub fn run(&self, lines: Vec<String>, parts: Parts) -> Result<Output> {
+        match self {
+            Solver::Whole(solve) => {
+                let (answer_a, answer_b) = solve(lines)?;
+                Ok(Output {
+                    answer_a: parts
+                        .has_part1()
+                        .then(|| answer_a.map(Answer::from)),
+                    answer_b: parts
+                        .has_part2()
+                        .then(|| answer_b.map(Answer::from)),
+                    timings: Timings::default(),
+                })
+            }
+            Solver::Staged(run) => run(lines, parts),
+        }
+    }
+
+    /// Whether parsing, Part 1 and Part 2 can be run and timed separately
+    pub fn is_staged(&self) -> bool {
+        matches!(self, Solver::Staged(_))
     }
 }
 
+/// Solver for some year and day.
+/// Returns an error if the puzzle has not been implemented.
+pub fn solve(yr: u8, day: u8) -> Result<Solver> {
+    registry::Registry::new().lookup(yr, day)
+}
+
 /// For testing
 pub fn test_puzzle(puzzle: &puzzles::Puzzle) {
     let puzzles::Puzzle { year, day, input_data, answer_a, answer_b } = puzzle;
@@ -29,13 +79,18 @@ pub fn test_puzzle(puzzle: &puzzles::Puzzle) {
         .split('\n')
         .map(|s| s.to_string())
         .collect();
-    let (actual_a, actual_b) = solve(*year)(*day)(input).unwrap();
-    match answer_a {
-        Some(ans) => assert_eq!(*ans, actual_a.unwrap()),
-        None => (),
-    }
-    match answer_b {
-        Some(ans) => assert_eq!(*ans, actual_b.unwrap()),
-        None => (),
+    let output = solve(*year, *day).unwrap().run(input, Parts::Both).unwrap();
+    let (actual_a, actual_b) =
+        (output.answer_a.unwrap(), output.answer_b.unwrap());
+    for (expected, actual) in [(answer_a, actual_a), (answer_b, actual_b)] {
+        if let Some(expected) = expected {
+            let actual = actual.unwrap();
+            assert!(
+                actual.matches(expected),
+                "expected `{}`, got `{}`",
+                expected,
+                actual
+            );
+        }
     }
 }
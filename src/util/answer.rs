@@ -0,0 +1,143 @@
+use std::fmt::{self, Display};
+
+/// Answer to some part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// ASCII art drawn on a grid, where `pixels[y][x]` is true if lit.
+    /// `text` is the letters that the art spells out, if they could be recognized.
+    Grid {
+        pixels: Vec<Vec<bool>>,
+        text: Option<String>,
+    },
+}
+
+impl Answer {
+    /// Creates an Answer from the output of a solver,
+    /// which is an Integer if it looks like one, and Text otherwise.
+    /// Numbers with leading zeroes, e.g., `007`, remain Text.
+    pub fn parse(answer: &str) -> Answer {
+        match answer.parse::<i128>() {
+            Ok(n) if n.to_string() == answer => Answer::Integer(n),
+            _ => Answer::Text(answer.to_string()),
+        }
+    }
+
+    /// Whether the Answer matches some expected answer,
+    /// e.g., as written in `tests/outputs` or `tests/examples_yNN.rs`.
+    /// A Grid matches either its recognized text or its drawing.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Integer(n) => expected.trim().parse() == Ok(*n),
+            Answer::Text(text) => text == expected,
+            Answer::Grid { text, .. } => {
+                text.as_deref() == Some(expected) || self.draw() == expected
+            }
+        }
+    }
+
+    /// Name of the variant, e.g., for JSON output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::Text(_) => "text",
+            Answer::Grid { .. } => "grid",
+        }
+    }
+
+    /// Draws the Answer, where Grids are drawn with `█` for lit pixels.
+    /// Other answers are drawn as they are displayed.
+    pub fn draw(&self) -> String {
+        match self {
+            Answer::Grid { pixels, .. } => pixels
+                .iter()
+                .map(|row| {
+                    row.iter().map(|b| if *b { '█' } else { ' ' }).collect()
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// Grids are displayed as their recognized text if there is one,
+/// and drawn otherwise.
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid { text: Some(text), .. } => write!(f, "{}", text),
+            Answer::Grid { text: None, .. } => write!(f, "{}", self.draw()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::parse(&answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::parse(answer)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Answer::parse("42"), Answer::Integer(42));
+        assert_eq!(Answer::parse("-7"), Answer::Integer(-7));
+        assert_eq!(Answer::parse("azqje"), Answer::Text("azqje".to_string()));
+        assert_eq!(Answer::parse("007"), Answer::Text("007".to_string()));
+        assert_eq!(
+            Answer::from(18625484023687_u64).to_string(),
+            "18625484023687"
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::Integer(42).matches("42"));
+        assert!(!Answer::Integer(42).matches("41"));
+        assert!(Answer::Text("fnloekigdmpajchb".to_string())
+            .matches("fnloekigdmpajchb"));
+
+        let grid = Answer::Grid {
+            pixels: vec![vec![true, false], vec![false, true]],
+            text: None,
+        };
+        assert_eq!(grid.draw(), "█ \n █");
+        assert!(grid.matches("█ \n █"));
+        assert!(!grid.matches("AB"));
+        assert_eq!(grid.to_string(), "█ \n █");
+
+        let grid = Answer::Grid {
+            pixels: vec![vec![true, false], vec![false, true]],
+            text: Some("AB".to_string()),
+        };
+        assert!(grid.matches("AB"));
+        assert_eq!(grid.to_string(), "AB");
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde_json::{json, Map, Value};
+
+/// Summary statistics of repeated timings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Returns None if there are no samples.
+    pub fn new(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let n = samples.len();
+
+        let min = samples[0];
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        // Nearest-rank method, i.e., the smallest sample that is at least 95% of all samples
+        let p95 = samples[(n * 95).div_ceil(100) - 1];
+
+        Some(Stats { min, median, mean, p95 })
+    }
+
+    /// Whether the median is slower than the baseline's by more than `threshold`,
+    /// e.g., a threshold of 0.1 allows for the median to be 10% slower.
+    pub fn is_regression(&self, baseline: &Stats, threshold: f64) -> bool {
+        self.median.as_secs_f64()
+            > baseline.median.as_secs_f64() * (1.0 + threshold)
+    }
+
+    /// Represents the Stats as a JSON object of times in seconds.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "min": self.min.as_secs_f64(),
+            "median": self.median.as_secs_f64(),
+            "mean": self.mean.as_secs_f64(),
+            "p95": self.p95.as_secs_f64(),
+        })
+    }
+
+    pub fn from_json(value: &Value) -> Option<Stats> {
+        let get =
+            |key| Duration::try_from_secs_f64(value.get(key)?.as_f64()?).ok();
+        Some(Stats {
+            min: get("min")?,
+            median: get("median")?,
+            mean: get("mean")?,
+            p95: get("p95")?,
+        })
+    }
+}
+
+/// Stats of each stage of some puzzle, e.g., `"solve" => Stats`
+pub type StageStats = BTreeMap<String, Stats>;
+
+/// Baseline timings of many puzzles, keyed by `yNN-dNN`.
+/// Stored as a JSON file so that it can be compared across commits.
+#[derive(Default)]
+pub struct Baseline {
+    puzzles: BTreeMap<String, StageStats>,
+}
+
+impl Baseline {
+    /// Reads a Baseline from a file.
+    /// Returns an empty Baseline if the file does not exist.
+    pub fn read(path: &Path) -> Result<Baseline> {
+        if !path.is_file() {
+            return Ok(Baseline::default());
+        }
+        let contents = fs::read_to_string(path).with_context(|| {
+            format!("could not read baseline file {}", path.display())
+        })?;
+        let value: Value =
+            serde_json::from_str(&contents).with_context(|| {
+                format!("could not parse baseline file {}", path.display())
+            })?;
+
+        let mut puzzles = BTreeMap::new();
+        for (puzzle, stages) in value.as_object().into_iter().flatten() {
+            let stages = stages
+                .as_object()
+                .into_iter()
+                .flatten()
+                .filter_map(|(stage, stats)| {
+                    Some((stage.clone(), Stats::from_json(stats)?))
+                })
+                .collect();
+            puzzles.insert(puzzle.clone(), stages);
+        }
+        Ok(Baseline { puzzles })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let mut value = Map::new();
+        for (puzzle, stages) in &self.puzzles {
+            let stages = stages
+                .iter()
+                .map(|(stage, stats)| (stage.clone(), stats.to_json()))
+                .collect();
+            value.insert(puzzle.clone(), Value::Object(stages));
+        }
+        let contents = serde_json::to_string_pretty(&Value::Object(value))?;
+        fs::write(path, contents + "\n").with_context(|| {
+            format!("could not write baseline file {}", path.display())
+        })
+    }
+
+    pub fn get(&self, year: u8, day: u8) -> Option<&StageStats> {
+        self.puzzles.get(&Self::key(year, day))
+    }
+
+    pub fn insert(&mut self, year: u8, day: u8, stages: StageStats) {
+        self.puzzles.insert(Self::key(year, day), stages);
+    }
+
+    fn key(year: u8, day: u8) -> String {
+        format!("y{:02}-d{:02}", year, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::new(&[]), None);
+        assert_eq!(
+            Stats::new(&[ms(4), ms(1), ms(3), ms(2)]),
+            Some(Stats {
+                min: ms(1),
+                median: Duration::from_micros(2500),
+                mean: Duration::from_micros(2500),
+                p95: ms(4),
+            })
+        );
+
+        let samples = (1..=100).map(ms).collect::<Vec<_>>();
+        let stats = Stats::new(&samples).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.p95, ms(95));
+    }
+
+    #[test]
+    fn test_is_regression() {
+        let stats =
+            |median| Stats { min: ms(1), median, mean: ms(10), p95: ms(20) };
+        assert!(!stats(ms(10)).is_regression(&stats(ms(10)), 0.1));
+        assert!(!stats(ms(10)).is_regression(&stats(ms(20)), 0.1));
+        assert!(stats(ms(12)).is_regression(&stats(ms(10)), 0.1));
+    }
+
+    #[test]
+    fn test_stats_json() {
+        let stats =
+            Stats { min: ms(1), median: ms(2), mean: ms(3), p95: ms(4) };
+        assert_eq!(Stats::from_json(&stats.to_json()), Some(stats));
+        // Times that no Duration can hold
+        let mut negative = stats.to_json();
+        negative["mean"] = json!(-1.0);
+        assert_eq!(Stats::from_json(&negative), None);
+        let mut huge = stats.to_json();
+        huge["p95"] = json!(1e30);
+        assert_eq!(Stats::from_json(&huge), None);
+    }
+}
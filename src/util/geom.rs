@@ -0,0 +1,426 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::Integer;
+
+/// A Point in an infinite 2D grid.
+/// As in puzzle inputs, x grows to the East and y grows to the South.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A Vector is a Point that represents a displacement, e.g., `Direction::delta`.
+pub type Vector = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /// Sum of the absolute differences of each coordinate
+    pub fn manhattan(&self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Largest absolute difference of each coordinate,
+    /// i.e., how many king's moves it takes to reach other
+    pub fn chebyshev(&self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Points directly north, east, south, and west of self
+    pub fn neighbors4(&self) -> [Point; 4] {
+        Direction::ALL.map(|d| *self + d.delta())
+    }
+
+    /// Points surrounding self, including diagonals, clockwise from the North
+    pub fn neighbors8(&self) -> [Point; 8] {
+        Direction8::ALL.map(|d| *self + d.delta())
+    }
+
+    /// Converts a position `(x, y)` of a `util::grid::Grid` into a Point.
+    pub fn from_grid_pos((x, y): (usize, usize)) -> Point {
+        Point { x: x as i64, y: y as i64 }
+    }
+
+    /// Converts the Point into a position `(x, y)` of a width x height grid.
+    /// Returns None if the Point is outside of the grid.
+    pub fn to_grid_pos(
+        &self,
+        (width, height): (usize, usize),
+    ) -> Option<(usize, usize)> {
+        let (x, y) =
+            (usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?);
+        if x < width && y < height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point { x: self.x * rhs, y: self.y * rhs }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point { x: -self.x, y: -self.y }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// Twice the area of a polygon by the shoelace formula, which is always an integer.
+/// Vertices are listed in order around the polygon, either clockwise or counterclockwise,
+/// and the last vertex connects back to the first.
+/// The polygon must not cross itself.
+pub fn double_area(vertices: &[Point]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    // Products may overflow, even if the area does not
+    let sum = edges
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum::<i128>();
+    sum.abs() as i64
+}
+
+/// Number of points on the edges of a polygon, as in `double_area`
+pub fn boundary_points(vertices: &[Point]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges.map(|(a, b)| (a.x - b.x).abs().gcd(&(a.y - b.y).abs())).sum()
+}
+
+/// Number of points strictly inside a polygon, as in `double_area`,
+/// by [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem).
+pub fn interior_points(vertices: &[Point]) -> i64 {
+    (double_area(vertices) - boundary_points(vertices)) / 2 + 1
+}
+
+/// A Point in an infinite 3D grid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    /// Sum of the absolute differences of each coordinate
+    pub fn manhattan(&self, other: Point3) -> i64 {
+        (self.x - other.x).abs()
+            + (self.y - other.y).abs()
+            + (self.z - other.z).abs()
+    }
+
+    /// Largest absolute difference of each coordinate
+    pub fn chebyshev(&self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// Points that share a face with self
+    pub fn neighbors6(&self) -> [Point3; 6] {
+        [
+            Point3::new(-1, 0, 0),
+            Point3::new(1, 0, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, -1),
+            Point3::new(0, 0, 1),
+        ]
+        .map(|delta| *self + delta)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Point3) -> Point3 {
+        Point3 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Point3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Point3) -> Point3 {
+        Point3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Point3) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, rhs: i64) -> Point3 {
+        Point3 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3 { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
+}
+
+/// One of the four compass directions, where North is towards the top of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All Directions, clockwise from the North
+    pub const ALL: [Direction; 4] =
+        [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// The opposite Direction
+    pub fn rev(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// Turns 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// The Vector of a single step towards the Direction
+    pub fn delta(&self) -> Vector {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+}
+
+/// One of the eight compass directions, including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All Directions, clockwise from the North
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    fn index(&self) -> usize {
+        Direction8::ALL.iter().position(|d| d == self).unwrap()
+    }
+
+    /// The opposite Direction
+    pub fn rev(&self) -> Direction8 {
+        Direction8::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    pub fn turn_left(&self) -> Direction8 {
+        Direction8::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(&self) -> Direction8 {
+        Direction8::ALL[(self.index() + 1) % 8]
+    }
+
+    /// The Vector of a single step towards the Direction
+    pub fn delta(&self) -> Vector {
+        match self {
+            Direction8::North => Point::new(0, -1),
+            Direction8::NorthEast => Point::new(1, -1),
+            Direction8::East => Point::new(1, 0),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(0, 1),
+            Direction8::SouthWest => Point::new(-1, 1),
+            Direction8::West => Point::new(-1, 0),
+            Direction8::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        match direction {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, -2), Point::new(-3, 5));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(a + b, Point::new(-2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(-a * 2, Point::new(-2, 4));
+
+        let (a, b) = (Point3::new(1, 2, 3), Point3::new(4, 0, 3));
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(a.neighbors6().len(), 6);
+    }
+
+    #[test]
+    fn test_directions() {
+        for d in Direction::ALL {
+            assert_eq!(d.rev().rev(), d);
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.rev());
+            assert_eq!(d.delta() + d.rev().delta(), Point::ORIGIN);
+            assert_eq!(Direction8::from(d).delta(), d.delta());
+        }
+        for d in Direction8::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.delta() + d.rev().delta(), Point::ORIGIN);
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+    }
+
+    #[test]
+    fn test_polygon() {
+        // A 4x3 rectangle, with a 2x1 notch cut out of its bottom right
+        let vertices = [(0, 0), (4, 0), (4, 2), (2, 2), (2, 3), (0, 3)]
+            .map(|(x, y)| Point::new(x, y));
+        assert_eq!(double_area(&vertices), 2 * (4 * 3 - 2));
+        assert_eq!(boundary_points(&vertices), 4 + 2 + 2 + 1 + 2 + 3);
+        assert_eq!(interior_points(&vertices), 3 + 1);
+        // Listed counterclockwise, and with a point in the middle of an edge
+        let mut vertices = vertices.to_vec();
+        vertices.insert(1, Point::new(1, 0));
+        vertices.reverse();
+        assert_eq!(double_area(&vertices), 2 * (4 * 3 - 2));
+        assert_eq!(interior_points(&vertices), 3 + 1);
+        // Diagonal edges pass through points where both coordinates are integers
+        let triangle = [(0, 0), (4, 0), (0, 2)].map(|(x, y)| Point::new(x, y));
+        assert_eq!(double_area(&triangle), 8);
+        assert_eq!(boundary_points(&triangle), 4 + 2 + 2);
+        assert_eq!(interior_points(&triangle), 1);
+    }
+
+    #[test]
+    fn test_grid_pos() {
+        let p = Point::new(2, 1);
+        assert_eq!(p.to_grid_pos((3, 2)), Some((2, 1)));
+        assert_eq!(p.to_grid_pos((2, 2)), None);
+        assert_eq!(Point::new(-1, 0).to_grid_pos((3, 2)), None);
+        assert_eq!(Point::from_grid_pos((2, 1)), p);
+    }
+}
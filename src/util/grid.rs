@@ -0,0 +1,325 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use anyhow::{bail, Result};
+
+use crate::util::geom::{Direction, Point};
+
+/// A Grid is a width x height grid of cells, found in puzzles such as
+/// [y21-d20](https://adventofcode.com/2021/day/20) and [y23-d03](https://adventofcode.com/2023/day/3).
+/// Positions are `(x, y)`, where `(0, 0)` is the topleftmost cell
+/// and `(width - 1, height - 1)` is the bottomrightmost cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Cells in row-major order, i.e., cells[y * width + x]
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Only returns a Grid if rows is not empty, and all rows have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first()?.len();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Some(Grid { width, height, cells })
+    }
+
+    /// Creates a width x height Grid where cell (x, y) is f((x, y)).
+    pub fn from_fn<F>(width: usize, height: usize, f: F) -> Grid<T>
+    where
+        F: Fn((usize, usize)) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(f)
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    /// Parses lines into a Grid where each character is a cell.
+    /// Returns an error if lines is empty, if lines have different lengths,
+    /// or if `parse` returns None for some character.
+    pub fn parse_with<F>(lines: &[String], parse: F) -> Result<Grid<T>>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let width = match lines.first() {
+            Some(line) if !line.is_empty() => line.chars().count(),
+            _ => bail!("grid is empty"),
+        };
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let length = line.chars().count();
+            if length != width {
+                bail!("row {} has length {} instead of {}", y, length, width);
+            }
+            for (x, c) in line.chars().enumerate() {
+                match parse(c) {
+                    Some(cell) => cells.push(cell),
+                    None => bail!("invalid cell `{}` at ({},{})", c, x, y),
+                }
+            }
+        }
+        Ok(Grid { width, height: lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether (x, y) is within the Grid
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if !self.contains((x, y)) {
+            return None;
+        }
+        self.cells.get(y * self.width + x)
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if !self.contains((x, y)) {
+            return None;
+        }
+        self.cells.get_mut(y * self.width + x)
+    }
+
+    /// Iterates over the rows from top to bottom
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Iterates over the cells of column x from top to bottom
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Iterates over the columns from left to right
+    pub fn columns(&self) -> impl Iterator<Item = Vec<&T>> {
+        (0..self.width).map(|x| self.column(x).collect())
+    }
+
+    /// Iterates over all positions in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over all positions and their cells in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns the first position, in row-major order, whose cell satisfies the predicate.
+    pub fn find<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(pos, _)| pos)
+    }
+
+    /// Positions directly north, west, east, and south of (x, y) that are within the Grid
+    pub fn neighbors4(
+        &self,
+        (x, y): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+            .into_iter()
+            .filter_map(move |delta| self.offset((x, y), delta))
+    }
+
+    /// Positions surrounding (x, y), including diagonals, that are within the Grid
+    pub fn neighbors8(
+        &self,
+        (x, y): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)]
+            .into_iter()
+            .filter_map(move |delta| self.offset((x, y), delta))
+    }
+
+    /// Returns (x + dx, y + dy) if it is within the Grid
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        if self.contains(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position one step from pos towards direction if it is within the Grid
+    pub fn step(
+        &self,
+        pos: (usize, usize),
+        direction: Direction,
+    ) -> Option<(usize, usize)> {
+        (Point::from_grid_pos(pos) + direction.delta())
+            .to_grid_pos((self.width, self.height))
+    }
+
+    /// Creates a Grid of the same size whose cells are f applied to each cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a width x height Grid where all cells are value.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Swaps rows and columns, i.e., (x, y) becomes (y, x).
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotates the Grid 90 degrees clockwise.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the Grid 90 degrees counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirrors the Grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors the Grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+}
+
+impl Grid<char> {
+    /// Parses lines into a Grid of their characters.
+    pub fn parse(lines: &[String]) -> Result<Grid<char>> {
+        Grid::parse_with(lines, Some)
+    }
+}
+
+/// Panics if (x, y) is not within the Grid.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains((x, y)), "({},{}) is out of bounds", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+/// Panics if (x, y) is not within the Grid.
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains((x, y)), "({},{}) is out of bounds", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Displays each row on its own line, without a trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(lines: &[&str]) -> Grid<char> {
+        let lines = lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        Grid::parse(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let lines = vec!["123".to_string(), "456".to_string()];
+        let digits = Grid::parse_with(&lines, |c| c.to_digit(10)).unwrap();
+        assert_eq!((digits.width(), digits.height()), (3, 2));
+        assert_eq!(digits[(2, 0)], 3);
+        assert_eq!(digits.get((1, 1)), Some(&5));
+        assert_eq!(digits.get((3, 1)), None);
+        assert_eq!(digits.find(|d| *d > 3), Some((0, 1)));
+        assert_eq!(digits.column(1).collect::<Vec<_>>(), vec![&2, &5]);
+        assert_eq!(digits.to_string(), "123\n456");
+
+        let lines = vec!["123".to_string(), "45".to_string()];
+        assert!(Grid::parse_with(&lines, |c| c.to_digit(10)).is_err());
+        let lines = vec!["12x".to_string()];
+        assert!(Grid::parse_with(&lines, |c| c.to_digit(10)).is_err());
+        assert!(Grid::parse_with(&[], |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_transformations() {
+        let g = grid(&["abc", "def"]);
+        assert_eq!(g.transpose(), grid(&["ad", "be", "cf"]));
+        assert_eq!(g.rotate_clockwise(), grid(&["da", "eb", "fc"]));
+        assert_eq!(g.rotate_counterclockwise(), grid(&["cf", "be", "ad"]));
+        assert_eq!(g.flip_horizontal(), grid(&["cba", "fed"]));
+        assert_eq!(g.flip_vertical(), grid(&["def", "abc"]));
+        assert_eq!(
+            g.rotate_clockwise().rotate_clockwise(),
+            g.flip_horizontal().flip_vertical()
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let g = grid(&["abc", "def", "ghi"]);
+        assert_eq!(
+            g.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(g.neighbors4((1, 1)).count(), 4);
+        assert_eq!(
+            g.neighbors8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (1, 2)]
+        );
+        assert_eq!(g.neighbors8((1, 1)).count(), 8);
+    }
+}
@@ -0,0 +1,338 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use anyhow::{bail, Result};
+
+/// A Hex in a hexagonal grid, using axial coordinates `q` and `r`.
+/// The implicit third cube coordinate `s` satisfies `q + r + s == 0`.
+/// See <https://www.redblobgames.com/grids/hexagons/>.
+///
+/// The same coordinates work for both Orientations;
+/// only the names of the directions differ, see FlatDirection and PointyDirection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+/// Whether hexes have a flat edge or a vertex at the top
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Flat,
+    Pointy,
+}
+
+/// Whether odd or even columns (Flat) or rows (Pointy) are shoved by half a hex
+/// in offset coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parity {
+    Odd,
+    Even,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    /// Steps to each neighbor, counterclockwise.
+    /// That is, east, northeast, northwest, west, southwest, southeast for Pointy hexes,
+    /// and southeast, northeast, north, northwest, southwest, south for Flat hexes.
+    pub const DIRECTIONS: [Hex; 6] = [
+        Hex::new(1, 0),
+        Hex::new(1, -1),
+        Hex::new(0, -1),
+        Hex::new(-1, 0),
+        Hex::new(-1, 1),
+        Hex::new(0, 1),
+    ];
+
+    pub const fn new(q: i64, r: i64) -> Hex {
+        Hex { q, r }
+    }
+
+    /// Only returns a Hex if `q + r + s == 0`.
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Option<Hex> {
+        if q + r + s == 0 {
+            Some(Hex { q, r })
+        } else {
+            None
+        }
+    }
+
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Number of steps it takes to reach other
+    pub fn distance(&self, other: Hex) -> i64 {
+        let diff = *self - other;
+        (diff.q.abs() + diff.r.abs() + diff.s().abs()) / 2
+    }
+
+    pub fn neighbors(&self) -> [Hex; 6] {
+        Hex::DIRECTIONS.map(|d| *self + d)
+    }
+
+    /// Hexes that are exactly `radius` steps away, counterclockwise.
+    /// The ring of radius 0 is the Hex itself.
+    pub fn ring(&self, radius: i64) -> Vec<Hex> {
+        if radius <= 0 {
+            return vec![*self];
+        }
+        let mut result = Vec::with_capacity(6 * radius as usize);
+        let mut hex = *self + Hex::DIRECTIONS[4] * radius;
+        for direction in Hex::DIRECTIONS {
+            for _ in 0..radius {
+                result.push(hex);
+                hex += direction;
+            }
+        }
+        result
+    }
+
+    /// Hexes on the line from self to other, including both ends
+    pub fn line_to(&self, other: Hex) -> Vec<Hex> {
+        let n = self.distance(other);
+        if n == 0 {
+            return vec![*self];
+        }
+        // Nudge the line so that points on an edge consistently round to one side
+        let (q0, r0) = (self.q as f64 + 1e-6, self.r as f64 + 2e-6);
+        let (q1, r1) = (other.q as f64 + 1e-6, other.r as f64 + 2e-6);
+        (0..=n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                Hex::round(q0 + (q1 - q0) * t, r0 + (r1 - r0) * t)
+            })
+            .collect()
+    }
+
+    /// Rounds fractional axial coordinates to the nearest Hex.
+    fn round(q: f64, r: f64) -> Hex {
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        Hex::new(rq as i64, rr as i64)
+    }
+
+    /// Converts into offset coordinates `(col, row)`.
+    pub fn to_offset(
+        &self,
+        orientation: Orientation,
+        parity: Parity,
+    ) -> (i64, i64) {
+        match orientation {
+            Orientation::Flat => (self.q, self.r + shove(self.q, parity)),
+            Orientation::Pointy => (self.q + shove(self.r, parity), self.r),
+        }
+    }
+
+    /// Converts from offset coordinates `(col, row)`.
+    pub fn from_offset(
+        (col, row): (i64, i64),
+        orientation: Orientation,
+        parity: Parity,
+    ) -> Hex {
+        match orientation {
+            Orientation::Flat => Hex::new(col, row - shove(col, parity)),
+            Orientation::Pointy => Hex::new(col - shove(row, parity), row),
+        }
+    }
+}
+
+/// How far a column (Flat) or row (Pointy) at n is shoved in offset coordinates
+fn shove(n: i64, parity: Parity) -> i64 {
+    match parity {
+        Parity::Odd => (n - (n & 1)) / 2,
+        Parity::Even => (n + (n & 1)) / 2,
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Hex) -> Hex {
+        Hex { q: self.q + rhs.q, r: self.r + rhs.r }
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Hex) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Hex) -> Hex {
+        Hex { q: self.q - rhs.q, r: self.r - rhs.r }
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: i64) -> Hex {
+        Hex { q: self.q * rhs, r: self.r * rhs }
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Hex {
+        Hex { q: -self.q, r: -self.r }
+    }
+}
+
+/// Directions between Flat hexes, e.g., in [y17-d11](https://adventofcode.com/2017/day/11)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlatDirection {
+    N,
+    NE,
+    SE,
+    S,
+    SW,
+    NW,
+}
+
+impl FlatDirection {
+    /// Parses input "n", "ne", ... into a FlatDirection
+    pub fn parse(input: &str) -> Result<FlatDirection> {
+        match input {
+            "n" => Ok(FlatDirection::N),
+            "ne" => Ok(FlatDirection::NE),
+            "se" => Ok(FlatDirection::SE),
+            "s" => Ok(FlatDirection::S),
+            "sw" => Ok(FlatDirection::SW),
+            "nw" => Ok(FlatDirection::NW),
+            _ => bail!("invalid flat hex direction {}", input),
+        }
+    }
+
+    pub fn delta(&self) -> Hex {
+        match self {
+            FlatDirection::N => Hex::new(0, -1),
+            FlatDirection::NE => Hex::new(1, -1),
+            FlatDirection::SE => Hex::new(1, 0),
+            FlatDirection::S => Hex::new(0, 1),
+            FlatDirection::SW => Hex::new(-1, 1),
+            FlatDirection::NW => Hex::new(-1, 0),
+        }
+    }
+}
+
+/// Directions between Pointy hexes, e.g., in [y20-d24](https://adventofcode.com/2020/day/24)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyDirection {
+    E,
+    NE,
+    NW,
+    W,
+    SW,
+    SE,
+}
+
+impl PointyDirection {
+    /// Parses input "e", "ne", ... into a PointyDirection
+    pub fn parse(input: &str) -> Result<PointyDirection> {
+        match input {
+            "e" => Ok(PointyDirection::E),
+            "ne" => Ok(PointyDirection::NE),
+            "nw" => Ok(PointyDirection::NW),
+            "w" => Ok(PointyDirection::W),
+            "sw" => Ok(PointyDirection::SW),
+            "se" => Ok(PointyDirection::SE),
+            _ => bail!("invalid pointy hex direction {}", input),
+        }
+    }
+
+    pub fn delta(&self) -> Hex {
+        match self {
+            PointyDirection::E => Hex::new(1, 0),
+            PointyDirection::NE => Hex::new(1, -1),
+            PointyDirection::NW => Hex::new(0, -1),
+            PointyDirection::W => Hex::new(-1, 0),
+            PointyDirection::SW => Hex::new(-1, 1),
+            PointyDirection::SE => Hex::new(0, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let walk = |steps: &[&str]| {
+            steps
+                .iter()
+                .map(|s| FlatDirection::parse(s).unwrap().delta())
+                .fold(Hex::ORIGIN, |acc, d| acc + d)
+        };
+        assert_eq!(walk(&["ne", "ne", "ne"]).distance(Hex::ORIGIN), 3);
+        assert_eq!(walk(&["ne", "ne", "sw", "sw"]).distance(Hex::ORIGIN), 0);
+        assert_eq!(walk(&["ne", "ne", "s", "s"]).distance(Hex::ORIGIN), 2);
+        assert_eq!(
+            walk(&["se", "sw", "se", "sw", "sw"]).distance(Hex::ORIGIN),
+            3
+        );
+
+        let hex = Hex::new(2, -5);
+        assert_eq!(Hex::from_cube(2, -5, 3), Some(hex));
+        assert_eq!(Hex::from_cube(2, -5, 2), None);
+        assert!(hex.neighbors().iter().all(|n| n.distance(hex) == 1));
+    }
+
+    #[test]
+    fn test_ring_and_line() {
+        let center = Hex::new(1, 1);
+        assert_eq!(center.ring(0), vec![center]);
+        for radius in 1..4 {
+            let ring = center.ring(radius);
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|h| h.distance(center) == radius));
+        }
+
+        let (a, b) = (Hex::new(-2, 0), Hex::new(3, -4));
+        let line = a.line_to(b);
+        assert_eq!(line.len(), a.distance(b) as usize + 1);
+        assert_eq!((line[0], line[line.len() - 1]), (a, b));
+        assert!(line.windows(2).all(|w| w[0].distance(w[1]) == 1));
+    }
+
+    #[test]
+    fn test_offset() {
+        for hex in
+            Hex::new(0, 0).ring(2).into_iter().chain(Hex::new(-3, 1).ring(3))
+        {
+            for orientation in [Orientation::Flat, Orientation::Pointy] {
+                for parity in [Parity::Odd, Parity::Even] {
+                    let offset = hex.to_offset(orientation, parity);
+                    assert_eq!(
+                        Hex::from_offset(offset, orientation, parity),
+                        hex
+                    );
+                }
+            }
+        }
+        // Odd rows are shoved to the right
+        assert_eq!(
+            Hex::new(0, 1).to_offset(Orientation::Pointy, Parity::Odd),
+            (0, 1)
+        );
+        assert_eq!(
+            Hex::new(0, 1).to_offset(Orientation::Pointy, Parity::Even),
+            (1, 1)
+        );
+        assert_eq!(
+            Hex::new(1, 0).to_offset(Orientation::Flat, Parity::Odd),
+            (1, 0)
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use std::fmt;
+use std::ops::Range;
+
+use num::PrimInt;
+
+/// A half-open Interval of integers from start up to but excluding end,
+/// e.g., seed ranges in [y23-d05](https://adventofcode.com/2023/day/5).
+/// An Interval where `start >= end` is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    /// Interval from first to last, including both ends, as written in most puzzle inputs
+    pub fn from_inclusive(first: T, last: T) -> Interval<T> {
+        Interval { start: first, end: last + T::one() }
+    }
+
+    /// Interval of length values starting at start
+    pub fn with_length(start: T, length: T) -> Interval<T> {
+        Interval { start, end: start + length }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Number of values in the Interval
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of other is in self. An empty other is in any Interval.
+    pub fn contains_interval(&self, other: Interval<T>) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether self and other share at least one value
+    pub fn overlaps(&self, other: Interval<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Values in both self and other, which may be empty
+    pub fn intersection(&self, other: Interval<T>) -> Interval<T> {
+        Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        }
+    }
+
+    /// Splits the Interval into values less than at, and values at least at.
+    /// Either may be empty.
+    pub fn split_at(&self, at: T) -> (Interval<T>, Interval<T>) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (
+            Interval { start: self.start, end: at },
+            Interval { start: at, end: self.end },
+        )
+    }
+
+    /// Moves the Interval by delta
+    pub fn translate(&self, delta: T) -> Interval<T> {
+        Interval { start: self.start + delta, end: self.end + delta }
+    }
+
+    /// The values of the Interval, as a Range that can be iterated over
+    pub fn range(&self) -> Range<T> {
+        self.start..self.end
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{},{})", self.start, self.end)
+    }
+}
+
+/// A set of values stored as Intervals.
+/// The Intervals are kept sorted, non-empty, and neither overlapping nor touching,
+/// so two IntervalSets with the same values are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// The normalized Intervals, from lowest to highest
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::zero(), |acc, i| acc + i.len())
+    }
+
+    /// Lowest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// Highest value in the set
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.end - T::one())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // The last Interval that starts at or before value is the only one that could contain it
+        let index = self.intervals.partition_point(|i| i.start <= value);
+        index > 0 && self.intervals[index - 1].contains(value)
+    }
+
+    /// Adds all values of interval to the set.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // Intervals before `lo` end before interval, and intervals from `hi` start after it
+        let lo = self.intervals.partition_point(|i| i.end < interval.start);
+        let hi = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if lo < hi {
+            merged.start = merged.start.min(self.intervals[lo].start);
+            merged.end = merged.end.max(self.intervals[hi - 1].end);
+        }
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    /// Values in either self or other
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.insert(*interval);
+        }
+        result
+    }
+
+    /// Values in both self and other
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            let common = a.intersection(b);
+            if !common.is_empty() {
+                intervals.push(common);
+            }
+            // Whichever ends first cannot overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Values in self that are not in other
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = vec![];
+        let mut j = 0;
+        for &interval in &self.intervals {
+            let mut remaining = interval;
+            // Skip the Intervals of other that end before this one
+            while j < other.intervals.len()
+                && other.intervals[j].end <= remaining.start
+            {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.intervals.len()
+                && other.intervals[k].start < remaining.end
+            {
+                let (before, _) = remaining.split_at(other.intervals[k].start);
+                let (_, after) = remaining.split_at(other.intervals[k].end);
+                if !before.is_empty() {
+                    intervals.push(before);
+                }
+                remaining = after;
+                k += 1;
+            }
+            if !remaining.is_empty() {
+                intervals.push(remaining);
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Splits the set into values less than at, and values at least at.
+    pub fn split_at(&self, at: T) -> (IntervalSet<T>, IntervalSet<T>) {
+        let (mut lower, mut upper) = (vec![], vec![]);
+        for interval in &self.intervals {
+            let (below, above) = interval.split_at(at);
+            if !below.is_empty() {
+                lower.push(below);
+            }
+            if !above.is_empty() {
+                upper.push(above);
+            }
+        }
+        (IntervalSet { intervals: lower }, IntervalSet { intervals: upper })
+    }
+
+    /// Moves every value in the set by delta
+    pub fn translate(&self, delta: T) -> IntervalSet<T> {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|i| i.translate(delta))
+                .collect(),
+        }
+    }
+}
+
+impl<T: PrimInt> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::new();
+        result.insert(interval);
+        result
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(
+        iter: I,
+    ) -> IntervalSet<T> {
+        let mut intervals =
+            iter.into_iter().filter(|i| !i.is_empty()).collect::<Vec<_>>();
+        intervals.sort();
+        let mut result: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match result.last_mut() {
+                Some(last) if interval.start <= last.end => {
+                    last.end = last.end.max(interval.end);
+                }
+                _ => result.push(interval),
+            }
+        }
+        IntervalSet { intervals: result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// Values that tests draw Intervals from
+    const DOMAIN: Range<i32> = -2..10;
+
+    /// A pseudorandom generator of IntervalSets, so that the properties below
+    /// are checked against many sets without depending on an external crate
+    struct Generator(u64);
+
+    impl Generator {
+        fn next(&mut self) -> i32 {
+            // Linear congruential generator from Knuth's MMIX
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 33) as i32
+        }
+
+        fn interval(&mut self) -> Interval<i32> {
+            let width = DOMAIN.end - DOMAIN.start;
+            let start = DOMAIN.start + self.next().rem_euclid(width);
+            // Sometimes empty or backwards
+            Interval::new(start, start + self.next().rem_euclid(5) - 1)
+        }
+
+        fn set(&mut self) -> IntervalSet<i32> {
+            let count = self.next().rem_euclid(4);
+            (0..count).map(|_| self.interval()).collect()
+        }
+    }
+
+    fn values(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+        set.intervals().iter().flat_map(|i| i.range()).collect()
+    }
+
+    fn is_normalized(set: &IntervalSet<i32>) -> bool {
+        set.intervals().iter().all(|i| !i.is_empty())
+            && set.intervals().windows(2).all(|w| w[0].end < w[1].start)
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::from_inclusive(2, 6);
+        assert_eq!(a, Interval::new(2, 7));
+        assert_eq!(a, Interval::with_length(2, 5));
+        assert_eq!(a.len(), 5);
+        assert!(a.contains(6) && !a.contains(7));
+        assert!(a.contains_interval(Interval::new(3, 5)));
+        assert!(!a.contains_interval(Interval::new(3, 8)));
+        assert!(a.overlaps(Interval::new(6, 9)));
+        assert!(!a.overlaps(Interval::new(7, 9)));
+        assert_eq!(a.split_at(4), (Interval::new(2, 4), Interval::new(4, 7)));
+        assert!(a.split_at(0).0.is_empty());
+        assert!(a.split_at(9).1.is_empty());
+        assert_eq!(a.translate(-2), Interval::new(0, 5));
+        assert_eq!(Interval::new(3, 1).len(), 0);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a =
+            IntervalSet::from_iter([Interval::new(1, 4), Interval::new(6, 9)]);
+        let b = IntervalSet::from(Interval::new(3, 7));
+        assert_eq!(a.union(&b).intervals(), &[Interval::new(1, 9)]);
+        assert_eq!(
+            a.intersection(&b).intervals(),
+            &[Interval::new(3, 4), Interval::new(6, 7)]
+        );
+        assert_eq!(
+            a.difference(&b).intervals(),
+            &[Interval::new(1, 3), Interval::new(7, 9)]
+        );
+        assert_eq!(a.len(), 6);
+        assert_eq!((a.min(), a.max()), (Some(1), Some(8)));
+        // Touching Intervals are merged
+        let mut c = IntervalSet::from(Interval::new(1, 3));
+        c.insert(Interval::new(3, 5));
+        assert_eq!(c.intervals(), &[Interval::new(1, 5)]);
+    }
+
+    #[test]
+    fn test_set_properties() {
+        let mut gen = Generator(2023);
+        for _ in 0..2000 {
+            let (a, b) = (gen.set(), gen.set());
+            let (va, vb) = (values(&a), values(&b));
+            let at = gen.next().rem_euclid(14) - 3;
+            let delta = gen.next().rem_euclid(7) - 3;
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+            let (lower, upper) = a.split_at(at);
+            let translated = a.translate(delta);
+            for set in [&union, &intersection, &difference, &lower, &upper] {
+                assert!(is_normalized(set), "{:?} is not normalized", set);
+            }
+            assert!(is_normalized(&translated));
+
+            assert_eq!(values(&union), &va | &vb);
+            assert_eq!(values(&intersection), &va & &vb);
+            assert_eq!(values(&difference), &va - &vb);
+            assert_eq!(
+                values(&lower),
+                va.iter().copied().filter(|v| *v < at).collect()
+            );
+            assert_eq!(
+                values(&upper),
+                va.iter().copied().filter(|v| *v >= at).collect()
+            );
+            assert_eq!(
+                values(&translated),
+                va.iter().map(|v| v + delta).collect()
+            );
+            assert_eq!(a.len() as usize, va.len());
+            for v in DOMAIN {
+                assert_eq!(a.contains(v), va.contains(&v));
+            }
+            assert_eq!(union, b.union(&a));
+            assert_eq!(intersection, b.intersection(&a));
+        }
+    }
+}
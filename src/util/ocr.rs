@@ -0,0 +1,246 @@
+/// Letters of the 4x6 font, e.g., in [y21-d13](https://adventofcode.com/2021/day/13).
+/// Letters are one column apart.
+const FONT_6: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters of the 6x10 font, e.g., in [y18-d10](https://adventofcode.com/2018/day/10).
+/// Letters are two columns apart.
+const FONT_10: &[(char, [&str; 10])] = &[
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######",
+            "#....#", "#....#", "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#",
+            "#....#", "#....#", "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "#.....", "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....",
+            "#.....", "#.....", "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....",
+            "#.....", "#.....", "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###",
+            "#....#", "#....#", "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#",
+            "#....#", "#....#", "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.",
+            "....#.", "#...#.", "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....",
+            "#.#...", "#..#..", "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "#.....", "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#",
+            "#..#.#", "#...##", "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....",
+            "#.....", "#.....", "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..",
+            "#...#.", "#...#.", "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..",
+            ".#..#.", ".#..#.", "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...",
+            ".#....", "#.....", "#.....", "######",
+        ],
+    ),
+];
+
+/// Recognizes the letters drawn on a grid, where `pixels[y][x]` is true if lit.
+/// Supports the 4x6 and 6x10 fonts used by Advent of Code.
+/// Empty rows and columns around the letters are ignored.
+/// Returns None if the height does not match a font, or if some letter is unknown.
+pub fn recognize(pixels: &[Vec<bool>]) -> Option<String> {
+    let pixels = trim(pixels);
+    match pixels.len() {
+        6 => recognize_with(&pixels, FONT_6, 4, 1),
+        10 => recognize_with(&pixels, FONT_10, 6, 2),
+        _ => None,
+    }
+}
+
+fn recognize_with<const H: usize>(
+    pixels: &[Vec<bool>],
+    font: &[(char, [&str; H])],
+    letter_width: usize,
+    spacing: usize,
+) -> Option<String> {
+    let width = pixels[0].len();
+    let mut result = String::new();
+    for start in (0..width).step_by(letter_width + spacing) {
+        let glyph = pixels
+            .iter()
+            .map(|row| {
+                (start..start + letter_width)
+                    .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        let (letter, _) =
+            font.iter().find(|(_, rows)| rows[..] == glyph[..])?;
+        result.push(*letter);
+    }
+    Some(result)
+}
+
+/// Removes empty rows and columns around the lit pixels
+fn trim(pixels: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    let is_lit_row = |row: &Vec<bool>| row.iter().any(|b| *b);
+    let top = pixels.iter().position(is_lit_row);
+    let bottom = pixels.iter().rposition(is_lit_row);
+    let (top, bottom) = match (top, bottom) {
+        (Some(top), Some(bottom)) => (top, bottom),
+        _ => return vec![],
+    };
+    let rows = &pixels[top..=bottom];
+
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let is_lit_column =
+        |x: &usize| rows.iter().any(|row| row.get(*x) == Some(&true));
+    let left = (0..width).find(is_lit_column).unwrap_or(0);
+    let right = (0..width).rev().find(is_lit_column).unwrap_or(0);
+
+    rows.iter()
+        .map(|row| (left..=right).map(|x| row.get(x) == Some(&true)).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws the text in some font, padded by an empty border
+    fn draw<const H: usize>(
+        text: &str,
+        font: &[(char, [&str; H])],
+        spacing: usize,
+    ) -> Vec<Vec<bool>> {
+        let mut result = vec![vec![false; 1]; H + 2];
+        for c in text.chars() {
+            let (_, rows) =
+                font.iter().find(|(letter, _)| *letter == c).unwrap();
+            for y in 0..H {
+                result[y + 1].extend(rows[y].chars().map(|c| c == '#'));
+                result[y + 1].extend(vec![false; spacing]);
+            }
+        }
+        let width = result[1].len();
+        result[0].resize(width, false);
+        result[H + 1].resize(width, false);
+        result
+    }
+
+    #[test]
+    fn test_recognize_6() {
+        let letters = FONT_6.iter().map(|(c, _)| c).collect::<String>();
+        assert_eq!(recognize(&draw(&letters, FONT_6, 1)), Some(letters));
+
+        let art = "███  ████ █  █ █    █  █  ██  ████ ███
+█  █ █    █ █  █    █ █  █  █ █    █  █
+█  █ ███  ██   █    ██   █    ███  █  █
+███  █    █ █  █    █ █  █    █    ███
+█    █    █ █  █    █ █  █  █ █    █
+█    █    █  █ ████ █  █  ██  █    █   ";
+        let pixels = art
+            .lines()
+            .map(|line| line.chars().map(|c| c == '█').collect())
+            .collect::<Vec<_>>();
+        assert_eq!(recognize(&pixels), Some("PFKLKCFP".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_10() {
+        let letters = FONT_10.iter().map(|(c, _)| c).collect::<String>();
+        assert_eq!(recognize(&draw(&letters, FONT_10, 2)), Some(letters));
+    }
+
+    #[test]
+    fn test_recognize_unknown() {
+        assert_eq!(recognize(&[]), None);
+        assert_eq!(recognize(&vec![vec![true; 5]; 5]), None);
+        assert_eq!(recognize(&vec![vec![true; 4]; 6]), None);
+    }
+}
@@ -1,4 +1,5 @@
 use std::fs;
+use std::path::Path;
 
 use anyhow::{bail, Context, Result};
 
@@ -10,31 +11,51 @@ pub struct Puzzle {
     pub answer_b: Option<String>,
 }
 
+/// File name of the input and output of some puzzle, e.g., `y21-d05.txt`.
+pub fn file_name(year: u8, day: u8) -> String {
+    format!("y{:02}-d{:02}.txt", year, day)
+}
+
 /// Reads input filename and output filename. Assume that output_filename contains at least two lines.
-pub fn read_puzzle_from_files(
+/// Empty lines in output_filename are treated as unknown answers.
+pub fn read_puzzle_from_files<P: AsRef<Path>, Q: AsRef<Path>>(
     year: u8,
     day: u8,
-    input_filename: &str,
-    output_filename: &str,
+    input_filename: P,
+    output_filename: Q,
 ) -> Result<Puzzle> {
+    let (input_filename, output_filename) =
+        (input_filename.as_ref(), output_filename.as_ref());
     let input_data = fs::read_to_string(input_filename).with_context(|| {
-        format!("could not read input file {}", input_filename)
+        format!("could not read input file {}", input_filename.display())
     })?;
+    let (answer_a, answer_b) = read_answers(output_filename)?;
+
+    Ok(Puzzle { year, day, input_data, answer_a, answer_b })
+}
+
+/// Reads the expected answers for Part 1 and Part 2 from an output file.
+/// Assume that the file contains at least two lines, and empty lines are unknown answers.
+pub fn read_answers<P: AsRef<Path>>(
+    output_filename: P,
+) -> Result<(Option<String>, Option<String>)> {
+    let output_filename = output_filename.as_ref();
     let answers = fs::read_to_string(output_filename).with_context(|| {
-        format!("could not read output file {}", output_filename)
+        format!("could not read output file {}", output_filename.display())
     })?;
     let answers = answers.split('\n').collect::<Vec<_>>();
     if answers.len() < 2 {
-        bail!("output file {} too short", output_filename);
+        bail!("output file {} too short", output_filename.display());
     }
+    let answer =
+        |s: &str| if s.is_empty() { None } else { Some(s.to_string()) };
+
+    Ok((answer(answers[0]), answer(answers[1])))
+}
 
-    Ok(Puzzle {
-        year,
-        day,
-        input_data,
-        answer_a: Some(answers[0].to_string()),
-        answer_b: Some(answers[1].to_string()),
-    })
+/// Splits the contents of an input file into lines, like how stdin would be read.
+pub fn lines(input_data: &str) -> Vec<String> {
+    input_data.lines().map(|s| s.to_string()).collect()
 }
 
 /// Take in a year, and create a function to generate Puzzles in that given year.
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+
+use crate::util::Solver;
+use crate::{y17, y21, y22, y23};
+
+/// Registry of every implemented solver, keyed by `(year, day)`.
+/// Built from the `SOLVERS` table of each year module.
+pub struct Registry {
+    solvers: BTreeMap<(u8, u8), Solver>,
+}
+
+impl Registry {
+    /// Creates a Registry holding all solvers in this crate.
+    pub fn new() -> Registry {
+        let mut registry = Registry { solvers: BTreeMap::new() };
+        registry.add_year(17, y17::SOLVERS);
+        registry.add_year(21, y21::SOLVERS);
+        registry.add_year(22, y22::SOLVERS);
+        registry.add_year(23, y23::SOLVERS);
+        registry
+    }
+
+    fn add_year(&mut self, year: u8, solvers: &[(u8, Solver)]) {
+        for (day, solver) in solvers {
+            self.solvers.insert((year, *day), *solver);
+        }
+    }
+
+    /// Returns the solver for some year and day,
+    /// or an error if that puzzle has not been implemented.
+    pub fn lookup(&self, year: u8, day: u8) -> Result<Solver> {
+        if let Some(solver) = self.solvers.get(&(year, day)) {
+            return Ok(*solver);
+        }
+        if !self.years().contains(&year) {
+            bail!("year {} is not implemented", year);
+        }
+        bail!("day {} of year {} is not implemented", day, year)
+    }
+
+    /// Lists all implemented `(year, day)` pairs in increasing order.
+    pub fn list(&self) -> Vec<(u8, u8)> {
+        self.solvers.keys().copied().collect()
+    }
+
+    /// Lists all years with at least one implemented day.
+    pub fn years(&self) -> Vec<u8> {
+        let mut years: Vec<u8> =
+            self.solvers.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+
+    /// Lists all implemented days of some year.
+    /// Returns an empty vector if the year is not implemented.
+    pub fn days(&self, year: u8) -> Vec<u8> {
+        self.solvers
+            .keys()
+            .filter(|(yr, _)| *yr == year)
+            .map(|(_, day)| *day)
+            .collect()
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let registry = Registry::new();
+        assert!(registry.lookup(21, 1).is_ok());
+        assert!(registry.lookup(21, 26).is_err());
+        assert!(registry.lookup(99, 1).is_err());
+    }
+
+    #[test]
+    fn test_years_and_days() {
+        let registry = Registry::new();
+        assert_eq!(registry.years(), vec![17, 21, 22, 23]);
+        assert_eq!(registry.days(22), (1..=25).collect::<Vec<u8>>());
+        assert!(registry.days(99).is_empty());
+        assert_eq!(registry.list().first(), Some(&(17, 1)));
+    }
+}
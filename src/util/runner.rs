@@ -0,0 +1,223 @@
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+
+use crate::util::answer::Answer;
+use crate::util::puzzles::{self, file_name};
+use crate::util::solution::{Output, Parts, Timings};
+use crate::util::Solver;
+
+/// Answers for Part 1 and Part 2 of some puzzle, and how long it took to get them.
+pub struct Run {
+    pub year: u8,
+    pub day: u8,
+    /// None if Part 1 was not asked for
+    pub answer_a: Option<Result<Answer>>,
+    /// None if Part 2 was not asked for
+    pub answer_b: Option<Result<Answer>>,
+    /// How long each stage took, if the solver exposes them
+    pub timings: Timings,
+    /// How long the whole run took
+    pub elapsed: Duration,
+}
+
+/// Result of comparing an answer against its expected value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail,
+    /// Expected answer is unknown
+    Unchecked,
+}
+
+impl Check {
+    /// An answer that was not asked for is Unchecked.
+    pub fn new(
+        expected: &Option<String>,
+        actual: &Option<Result<Answer>>,
+    ) -> Check {
+        match (expected, actual) {
+            (None, _) | (_, None) => Check::Unchecked,
+            (Some(expected), Some(Ok(actual))) if actual.matches(expected) => {
+                Check::Pass
+            }
+            _ => Check::Fail,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Check::Pass => "pass",
+            Check::Fail => "fail",
+            Check::Unchecked => "unchecked",
+        }
+    }
+}
+
+impl Run {
+    /// Represents the Run as a JSON object of the form
+    /// `{year, day, part1: {answer, type|error, time}, part2: {...}, parse_time, solve_time}`.
+    /// Answers are strings, with the kind of Answer in `type`,
+    /// and Grids also have their drawing as an array of rows in `art`.
+    /// Times are in seconds.
+    /// Parts that were not asked for are null.
+    /// `parse_time` and the time of each part are null if the solver does not expose its stages.
+    pub fn to_json(&self) -> Value {
+        let seconds = |d: Option<Duration>| d.map(|d| d.as_secs_f64());
+        let solve_time = match self.timings.parse {
+            Some(parse_time) => self.elapsed.saturating_sub(parse_time),
+            None => self.elapsed,
+        };
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "part1": answer_to_json(&self.answer_a, self.timings.part1),
+            "part2": answer_to_json(&self.answer_b, self.timings.part2),
+            "parse_time": seconds(self.timings.parse),
+            "solve_time": solve_time.as_secs_f64(),
+        })
+    }
+}
+
+fn answer_to_json(
+    answer: &Option<Result<Answer>>,
+    time: Option<Duration>,
+) -> Value {
+    let mut json = match answer {
+        None => return Value::Null,
+        Some(Ok(answer)) => {
+            let mut json = json!({
+                "answer": answer.to_string(),
+                "type": answer.kind(),
+            });
+            if let Answer::Grid { .. } = answer {
+                json["art"] = answer.draw().lines().collect::<Vec<_>>().into();
+            }
+            json
+        }
+        Some(Err(e)) => json!({ "error": format!("{:#}", e) }),
+    };
+    if let Some(time) = time {
+        json["time"] = time.as_secs_f64().into();
+    }
+    json
+}
+
+/// Runs the solver against the lines of some input, solving only the parts asked for.
+/// Returns an error if the input itself could not be parsed.
+pub fn run(
+    solver: Solver,
+    year: u8,
+    day: u8,
+    lines: Vec<String>,
+    parts: Parts,
+) -> Result<Run> {
+    let before = Instant::now();
+    let Output { answer_a, answer_b, timings } = solver
+        .run(lines, parts)
+        .with_context(|| format!("could not solve y{:02}-d{:02}", year, day))?;
+    let elapsed = before.elapsed();
+
+    Ok(Run { year, day, answer_a, answer_b, timings, elapsed })
+}
+
+/// Reads the input of some puzzle from `inputs_dir`,
+/// following the `yNN-dNN.txt` naming used in `tests/inputs`.
+pub fn read_input(inputs_dir: &Path, year: u8, day: u8) -> Result<Vec<String>> {
+    read_input_file(&inputs_dir.join(file_name(year, day)))
+}
+
+/// Reads the input of some puzzle from a file.
+pub fn read_input_file(path: &Path) -> Result<Vec<String>> {
+    if !path.is_file() {
+        bail!("input file {} does not exist", path.display());
+    }
+    let input_data = fs::read_to_string(path).with_context(|| {
+        format!("could not read input file {}", path.display())
+    })?;
+    Ok(puzzles::lines(&input_data))
+}
+
+/// Reads the expected answers of some puzzle from `outputs_dir`.
+/// Returns unknown answers if the output file does not exist.
+pub fn read_expected(
+    outputs_dir: &Path,
+    year: u8,
+    day: u8,
+) -> Result<(Option<String>, Option<String>)> {
+    let path = outputs_dir.join(file_name(year, day));
+    if !path.is_file() {
+        return Ok((None, None));
+    }
+    puzzles::read_answers(&path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_check() {
+        let expected = Some("42".to_string());
+        let answer = |s: &str| Some(Ok(Answer::parse(s)));
+        assert_eq!(Check::new(&expected, &answer("42")), Check::Pass);
+        assert_eq!(Check::new(&expected, &answer("41")), Check::Fail);
+        assert_eq!(
+            Check::new(&expected, &Some(Err(anyhow!("oops")))),
+            Check::Fail
+        );
+        assert_eq!(Check::new(&None, &answer("42")), Check::Unchecked);
+        assert_eq!(Check::new(&expected, &None), Check::Unchecked);
+    }
+
+    #[test]
+    fn test_run_to_json() {
+        let run = Run {
+            year: 21,
+            day: 13,
+            answer_a: Some(Ok(Answer::Integer(17))),
+            answer_b: Some(Err(anyhow!("cannot fold"))),
+            timings: Timings::default(),
+            elapsed: Duration::from_millis(1500),
+        };
+        assert_eq!(
+            run.to_json(),
+            json!({
+                "year": 21,
+                "day": 13,
+                "part1": { "answer": "17", "type": "integer" },
+                "part2": { "error": "cannot fold" },
+                "parse_time": null,
+                "solve_time": 1.5,
+            })
+        );
+
+        let run = Run {
+            year: 23,
+            day: 8,
+            answer_a: None,
+            answer_b: Some(Ok(Answer::Integer(6))),
+            timings: Timings {
+                parse: Some(Duration::from_millis(500)),
+                part1: None,
+                part2: Some(Duration::from_millis(250)),
+            },
+            elapsed: Duration::from_millis(750),
+        };
+        assert_eq!(
+            run.to_json(),
+            json!({
+                "year": 23,
+                "day": 8,
+                "part1": null,
+                "part2": { "answer": "6", "type": "integer", "time": 0.25 },
+                "parse_time": 0.5,
+                "solve_time": 0.25,
+            })
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num::Zero;
+use priority_queue::DoublePriorityQueue;
+
+/// Finds the cheapest path from start to a node where is_goal is true.
+/// `neighbors(node)` lists the nodes reachable from node, and how much it costs to step there.
+/// Returns the total cost and the path, including start and the goal,
+/// or None if no goal is reachable.
+pub fn dijkstra<N, C, FN, IN, FG>(
+    start: N,
+    neighbors: FN,
+    is_goal: FG,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, neighbors, |_| C::zero(), is_goal)
+}
+
+/// Like dijkstra, but visits nodes in order of their cost plus `heuristic(node)`,
+/// which estimates the cost from node to the nearest goal.
+/// The heuristic must never overestimate, and must not decrease by more than
+/// the cost of any step, or else the path found may not be the cheapest.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    mut neighbors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    // Cheapest known cost of reaching some node, and the node before it
+    let mut best: HashMap<N, (C, Option<N>)> = HashMap::new();
+    let mut done = HashSet::new();
+    let mut queue = DoublePriorityQueue::new();
+    best.insert(start.clone(), (C::zero(), None));
+    queue.push(start.clone(), heuristic(&start));
+
+    while let Some((node, _)) = queue.pop_min() {
+        let cost = best[&node].0;
+        if is_goal(&node) {
+            let path = reconstruct_path(&best, node, |(_, parent)| parent);
+            return Some((cost, path));
+        }
+        done.insert(node.clone());
+
+        for (next, step_cost) in neighbors(&node) {
+            if done.contains(&next) {
+                continue;
+            }
+            let next_cost = cost + step_cost;
+            if best.get(&next).is_some_and(|(c, _)| *c <= next_cost) {
+                continue;
+            }
+            queue.push_decrease(next.clone(), next_cost + heuristic(&next));
+            best.insert(next, (next_cost, Some(node.clone())));
+        }
+    }
+
+    None
+}
+
+/// Finds the path from start to a node where is_goal is true with the fewest steps.
+/// Returns the number of steps and the path, including start and the goal,
+/// or None if no goal is reachable.
+pub fn bfs<N, FN, IN, FG>(
+    start: N,
+    mut neighbors: FN,
+    mut is_goal: FG,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    parents.insert(start.clone(), None);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let path = reconstruct_path(&parents, node, |parent| parent);
+            return Some((path.len() - 1, path));
+        }
+        for next in neighbors(&node) {
+            if parents.contains_key(&next) {
+                continue;
+            }
+            parents.insert(next.clone(), Some(node.clone()));
+            queue.push_back(next);
+        }
+    }
+
+    None
+}
+
+/// Finds how many steps it takes to reach each node reachable from start.
+pub fn bfs_all_distances<N, FN, IN>(
+    start: N,
+    mut neighbors: FN,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    distances.insert(start.clone(), 0);
+    queue.push_back((start, 0));
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbors(&node) {
+            if distances.contains_key(&next) {
+                continue;
+            }
+            distances.insert(next.clone(), distance + 1);
+            queue.push_back((next, distance + 1));
+        }
+    }
+
+    distances
+}
+
+/// Follows the parents from goal back to the node without one,
+/// and returns the nodes in between from first to last.
+fn reconstruct_path<N, V, F>(
+    visited: &HashMap<N, V>,
+    goal: N,
+    parent: F,
+) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    F: Fn(&V) -> &Option<N>,
+{
+    let mut path = vec![goal];
+    while let Some(Some(prev)) = visited.get(path.last().unwrap()).map(&parent)
+    {
+        path.push(prev.clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Graph from https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        let all = [
+            (1, 2, 7),
+            (1, 3, 9),
+            (1, 6, 14),
+            (2, 3, 10),
+            (2, 4, 15),
+            (3, 4, 11),
+            (3, 6, 2),
+            (4, 5, 6),
+            (5, 6, 9),
+        ];
+        all.iter()
+            .filter_map(|&(a, b, cost)| match *node {
+                n if n == a => Some((b, cost)),
+                n if n == b => Some((a, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra(1, edges, |n| *n == 5),
+            Some((20, vec![1, 3, 6, 5]))
+        );
+        assert_eq!(dijkstra(1, edges, |n| *n == 1), Some((0, vec![1])));
+        assert_eq!(dijkstra(1, edges, |n| *n == 7), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walls at x == 2 except at y == 4
+        let neighbors = |&(x, y): &(i32, i32)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| {
+                    (0..5).contains(&x)
+                        && (0..5).contains(&y)
+                        && (x != 2 || y == 4)
+                })
+                .map(|pos| (pos, 1))
+                .collect::<Vec<_>>()
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (4 - x).abs() + y.abs();
+        let (cost, path) =
+            astar((0, 0), neighbors, heuristic, |pos| *pos == (4, 0)).unwrap();
+        assert_eq!(cost, 12);
+        assert_eq!(path.len(), 13);
+        assert!(path.contains(&(2, 4)));
+        assert_eq!(
+            dijkstra((0, 0), neighbors, |pos| *pos == (4, 0)).unwrap().0,
+            12
+        );
+    }
+
+    #[test]
+    fn test_bfs() {
+        let neighbors = |n: &u32| edges(n).into_iter().map(|(n, _)| n);
+        let (steps, path) = bfs(1, neighbors, |n| *n == 5).unwrap();
+        assert_eq!(steps, 2);
+        assert_eq!(path, vec![1, 6, 5]);
+        assert_eq!(bfs(1, neighbors, |n| *n == 7), None);
+
+        let distances = bfs_all_distances(1, neighbors);
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&1], 0);
+        assert_eq!(distances[&4], 2);
+        assert_eq!(distances[&5], 2);
+    }
+}
@@ -0,0 +1,120 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::util::answer::Answer;
+
+/// A puzzle solver split into stages, so that callers can run only one part,
+/// time parsing separately, or reuse the parsed model.
+///
+/// Implemented on a unit struct per day, e.g., `pub struct Day08;`,
+/// and registered with `Solver::staged::<Day08>()`.
+pub trait Solution {
+    /// Parsed model of the puzzle input
+    type Input;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+/// Which parts of a puzzle to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    pub fn has_part1(&self) -> bool {
+        *self != Parts::Two
+    }
+
+    pub fn has_part2(&self) -> bool {
+        *self != Parts::One
+    }
+}
+
+/// How long each stage of a solver took.
+/// Stages are None if they were not run, or if the solver does not expose them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+/// Answers of a solver, which are None for parts that were not asked for
+pub struct Output {
+    pub answer_a: Option<Result<Answer>>,
+    pub answer_b: Option<Result<Answer>>,
+    pub timings: Timings,
+}
+
+/// Runs the stages of some Solution, timing each one.
+/// Returns an error if the input could not be parsed.
+pub fn run_staged<S: Solution>(
+    lines: Vec<String>,
+    parts: Parts,
+) -> Result<Output> {
+    let before = Instant::now();
+    let input = S::parse(lines)?;
+    let mut timings =
+        Timings { parse: Some(before.elapsed()), ..Default::default() };
+
+    let mut answer_a = None;
+    if parts.has_part1() {
+        let before = Instant::now();
+        answer_a = Some(S::part1(&input));
+        timings.part1 = Some(before.elapsed());
+    }
+    let mut answer_b = None;
+    if parts.has_part2() {
+        let before = Instant::now();
+        answer_b = Some(S::part2(&input));
+        timings.part2 = Some(before.elapsed());
+    }
+
+    Ok(Output { answer_a, answer_b, timings })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(lines: Vec<String>) -> Result<Vec<u32>> {
+            lines.iter().map(|l| l.parse().context("not a number")).collect()
+        }
+
+        fn part1(input: &Vec<u32>) -> Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part2(input: &Vec<u32>) -> Result<Answer> {
+            Ok((*input.iter().max().context("empty input")?).into())
+        }
+    }
+
+    #[test]
+    fn test_run_staged() {
+        let lines = vec!["1".to_string(), "3".to_string()];
+        let output = run_staged::<Sum>(lines.clone(), Parts::Both).unwrap();
+        assert_eq!(output.answer_a.unwrap().unwrap(), Answer::Integer(4));
+        assert_eq!(output.answer_b.unwrap().unwrap(), Answer::Integer(3));
+        assert!(output.timings.parse.is_some());
+
+        let output = run_staged::<Sum>(lines, Parts::Two).unwrap();
+        assert!(output.answer_a.is_none());
+        assert!(output.timings.part1.is_none());
+        assert_eq!(output.answer_b.unwrap().unwrap(), Answer::Integer(3));
+
+        assert!(run_staged::<Sum>(vec!["x".to_string()], Parts::Both).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use crate::util::puzzles::file_name;
+use crate::util::registry::Registry;
+
+/// Status of some puzzle, i.e., whether it has been implemented,
+/// and what test data exists for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Status {
+    pub year: u8,
+    pub day: u8,
+    /// Solver exists in the Registry
+    pub implemented: bool,
+    /// `inputs/yNN-dNN.txt` exists in the tests directory
+    pub has_input: bool,
+    /// `outputs/yNN-dNN.txt` exists in the tests directory
+    pub has_output: bool,
+    /// `examples_yNN.rs` contains at least one example for the day
+    pub has_examples: bool,
+}
+
+/// Collects the Status of every day for every year in the Registry.
+/// `tests_dir` is the folder containing `inputs/`, `outputs/` and `examples_yNN.rs`.
+/// Missing files and folders are treated as missing test data.
+pub fn collect(registry: &Registry, tests_dir: &Path) -> Vec<Status> {
+    let mut result = Vec::new();
+    for year in registry.years() {
+        let implemented_days = registry.days(year);
+        let example_days = fs::read_to_string(
+            tests_dir.join(format!("examples_y{:02}.rs", year)),
+        )
+        .map(|contents| example_days(&contents))
+        .unwrap_or_default();
+
+        for day in 1..=25 {
+            let file_name = file_name(year, day);
+            result.push(Status {
+                year,
+                day,
+                implemented: implemented_days.contains(&day),
+                has_input: tests_dir.join("inputs").join(&file_name).is_file(),
+                has_output: tests_dir
+                    .join("outputs")
+                    .join(&file_name)
+                    .is_file(),
+                has_examples: example_days.contains(&day),
+            });
+        }
+    }
+    result
+}
+
+/// Looks for the days in an `examples_yNN.rs` file.
+/// Each example is a `puzzle(day, input, answer_a, answer_b)` call.
+fn example_days(contents: &str) -> BTreeSet<u8> {
+    contents
+        .split("puzzle(")
+        .skip(1)
+        .filter_map(|call| call.split(',').next()?.trim().parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_days() {
+        let contents = "let puzzle = puzzle_generator(21);
+    vec![
+        puzzle(1, \"199\", \"7\", \"5\"),
+        puzzle(
+            13,
+            \"6,10\",
+            \"17\",
+            \"\",
+        ),
+    ]
+    for puzzle in &puzzles {
+        test_puzzle(puzzle);
+    }";
+        assert_eq!(example_days(contents), BTreeSet::from([1, 13]));
+    }
+}
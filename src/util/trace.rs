@@ -0,0 +1,216 @@
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+use anyhow::{bail, Context, Result};
+
+/// Environment variable that configures tracing as a Filter,
+/// e.g., `AOC_TRACE=debug` or `AOC_TRACE=info,y23::d12=trace`.
+/// It takes precedence over `--verbose`.
+pub const ENV_VAR: &str = "AOC_TRACE";
+
+/// How detailed a trace message is, from least to most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] =
+        [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+
+    /// Level for the number of times `--verbose` is given, if any
+    pub fn from_verbosity(verbosity: u64) -> Option<Level> {
+        match verbosity {
+            0 => None,
+            1 => Some(Level::Info),
+            2 => Some(Level::Debug),
+            _ => Some(Level::Trace),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Level> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.name().eq_ignore_ascii_case(s))
+            .with_context(|| format!("invalid trace level `{}`", s))
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Decides which messages are written, given their Level and target.
+/// A target is the module that wrote the message without the crate name, e.g., `y23::d12`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    /// Most detailed Level written for targets not in `targets`, if any
+    default: Option<Level>,
+    /// Most detailed Level written for a target and the modules within it
+    targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    /// Writes messages up to level for all targets.
+    pub fn new(level: Option<Level>) -> Filter {
+        Filter { default: level, targets: vec![] }
+    }
+
+    /// Parses a comma-separated list of `level` or `target=level`,
+    /// e.g., "info,y23::d12=trace".
+    pub fn parse(spec: &str) -> Result<Filter> {
+        let mut filter = Filter::default();
+        for directive in spec.split(',').map(str::trim) {
+            if directive.is_empty() {
+                continue;
+            }
+            match directive.split_once('=') {
+                None => filter.default = Some(directive.parse()?),
+                Some((target, level)) => {
+                    if target.is_empty() {
+                        bail!("missing target in `{}`", directive);
+                    }
+                    filter.targets.push((target.to_string(), level.parse()?));
+                }
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Whether a message of level from target is written.
+    /// The longest target in the Filter that contains target decides.
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let target = strip_crate(target);
+        let most_specific = self
+            .targets
+            .iter()
+            .filter(|(t, _)| {
+                target == t
+                    || target
+                        .strip_prefix(t.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .max_by_key(|(t, _)| t.len());
+        match most_specific {
+            Some((_, max_level)) => level <= *max_level,
+            None => self.default.is_some_and(|max_level| level <= max_level),
+        }
+    }
+
+    /// Most detailed Level that is written for some target
+    fn max_level(&self) -> Option<Level> {
+        self.targets.iter().map(|(_, level)| *level).chain(self.default).max()
+    }
+}
+
+static FILTER: RwLock<Filter> =
+    RwLock::new(Filter { default: None, targets: Vec::new() });
+
+/// `max_level() as u8 + 1`, or 0 if nothing is written,
+/// so that disabled messages are skipped without locking FILTER
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Replaces the Filter that decides which messages are written.
+pub fn set_filter(filter: Filter) {
+    let max_level = filter.max_level().map_or(0, |level| level as u8 + 1);
+    *FILTER.write().unwrap() = filter;
+    MAX_LEVEL.store(max_level, Ordering::Relaxed);
+}
+
+/// Sets up tracing from `AOC_TRACE` if it is set,
+/// or else from the number of times `--verbose` is given.
+pub fn init(verbosity: u64) -> Result<()> {
+    let filter = match env::var(ENV_VAR) {
+        Ok(spec) => Filter::parse(&spec)
+            .with_context(|| format!("invalid {} `{}`", ENV_VAR, spec))?,
+        Err(_) => Filter::new(Level::from_verbosity(verbosity)),
+    };
+    set_filter(filter);
+    Ok(())
+}
+
+/// Whether a message of level from target would be written.
+/// Used by the `trace!` macro.
+pub fn enabled(level: Level, target: &str) -> bool {
+    (level as u8) < MAX_LEVEL.load(Ordering::Relaxed)
+        && FILTER.read().unwrap().enabled(level, target)
+}
+
+/// Writes a message to stderr, so that it never mixes with the answers.
+/// Used by the `trace!` macro.
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    eprintln!("[{} {}] {}", level, strip_crate(target), args);
+}
+
+/// Removes the crate name from a module path
+fn strip_crate(target: &str) -> &str {
+    target.strip_prefix("aoc_rs::").unwrap_or(target)
+}
+
+/// Writes a message to stderr if tracing is enabled for the Level and the calling module,
+/// e.g., `trace!(Debug, "{} has {} arrangements", springs, count)`.
+/// See `util::trace::init`.
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::util::trace::enabled(
+            $crate::util::trace::Level::$level,
+            module_path!(),
+        ) {
+            $crate::util::trace::write(
+                $crate::util::trace::Level::$level,
+                module_path!(),
+                format_args!($($arg)+),
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter::parse("info, y23::d12=trace,y23=warn").unwrap();
+        assert!(filter.enabled(Level::Info, "aoc_rs::y21::d21"));
+        assert!(!filter.enabled(Level::Debug, "aoc_rs::y21::d21"));
+        assert!(filter.enabled(Level::Trace, "aoc_rs::y23::d12"));
+        assert!(filter.enabled(Level::Trace, "y23::d12::tests"));
+        assert!(!filter.enabled(Level::Info, "aoc_rs::y23::d03"));
+        assert!(!filter.enabled(Level::Info, "aoc_rs::y23::d120"));
+        assert_eq!(filter.max_level(), Some(Level::Trace));
+
+        let filter = Filter::new(Level::from_verbosity(2));
+        assert!(filter.enabled(Level::Debug, "aoc_rs::y23::d12"));
+        assert!(!filter.enabled(Level::Trace, "aoc_rs::y23::d12"));
+        assert!(!Filter::new(None).enabled(Level::Error, "aoc_rs"));
+
+        assert!(Filter::parse("verbose").is_err());
+        assert!(Filter::parse("=debug").is_err());
+        assert_eq!("Debug".parse::<Level>().unwrap(), Level::Debug);
+    }
+}
@@ -2,11 +2,13 @@ use std::collections::HashMap;
 
 use anyhow::{bail, Result};
 
+use crate::util::geom::Point;
+
 // Part 1: Get the location of a number in the infinite grid
-fn number_to_location(input: u32) -> (i32, i32) {
+fn number_to_location(input: u32) -> Point {
     // (2a+1)^2 is the smallest odd square that isn't greater than input
-    let input = input as i32;
-    let a = ((f64::from(input).sqrt() - 1.0) / 2.0).floor() as i32;
+    let input = input as i64;
+    let a = (((input as f64).sqrt() - 1.0) / 2.0).floor() as i64;
     let (mut x, mut y) = (a, -a);
     // ptr is our point in the grid
     let mut ptr = (2 * a + 1) * (2 * a + 1);
@@ -40,40 +42,40 @@ fn number_to_location(input: u32) -> (i32, i32) {
         x += to_add;
     }
 
-    (x, y)
+    Point::new(x, y)
 }
 
 // Part 2: Create an Iterator that goes around the grid
 struct SpiralMemory {
-    grid: HashMap<(i32, i32), u32>,
-    pos: (i32, i32),
+    grid: HashMap<Point, u32>,
+    pos: Point,
 }
 
 impl SpiralMemory {
     pub fn new() -> SpiralMemory {
-        SpiralMemory { grid: HashMap::new(), pos: (0, 0) }
+        SpiralMemory { grid: HashMap::new(), pos: Point::ORIGIN }
     }
 
-    fn next_pos((x, y): (i32, i32)) -> (i32, i32) {
+    fn next_pos(Point { x, y }: Point) -> Point {
         // Case 0: pos moves to the next square
         if x >= 0 && x == -y {
-            return (x + 1, y);
+            return Point::new(x + 1, y);
         }
         // Case 1: pos could go upwards
         if x > y && x + y >= 0 {
-            return (x, y + 1);
+            return Point::new(x, y + 1);
         }
         // Case 2: pos can go leftwards
         if x <= y && x + y > 0 {
-            return (x - 1, y);
+            return Point::new(x - 1, y);
         }
         // Case 3: pos can go downwards
         if x < y && x + y <= 0 {
-            return (x, y - 1);
+            return Point::new(x, y - 1);
         }
         // Case 4: pos can go leftwards
         if x >= y && x + y <= 0 {
-            return (x + 1, y);
+            return Point::new(x + 1, y);
         }
 
         unimplemented!("unexpected case with x=={x} and y=={y}");
@@ -81,26 +83,18 @@ impl SpiralMemory {
 }
 
 impl Iterator for SpiralMemory {
-    type Item = ((i32, i32), u32);
+    type Item = (Point, u32);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.pos == (0, 0) {
+        if self.pos == Point::ORIGIN {
             self.grid.insert(self.pos, 1);
-            self.pos = (1, 0);
+            self.pos = Point::new(1, 0);
             return Some((self.pos, 1));
         }
 
-        let neighbors = [
-            (self.pos.0 - 1, self.pos.1 - 1),
-            (self.pos.0 - 1, self.pos.1),
-            (self.pos.0 - 1, self.pos.1 + 1),
-            (self.pos.0, self.pos.1 - 1),
-            (self.pos.0, self.pos.1 + 1),
-            (self.pos.0 + 1, self.pos.1 - 1),
-            (self.pos.0 + 1, self.pos.1),
-            (self.pos.0 + 1, self.pos.1 + 1),
-        ];
-        let neighbor_sum: u32 = neighbors
+        let neighbor_sum: u32 = self
+            .pos
+            .neighbors8()
             .into_iter()
             .map(|p| self.grid.get(&p).unwrap_or(&0))
             .sum();
@@ -118,8 +112,8 @@ pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
     let input: u32 = lines[0].parse().unwrap();
 
     // Part 1: Get the Manhattan distance
-    let (x, y) = number_to_location(input);
-    let ans1 = Ok((x.abs() + y.abs()).to_string());
+    let location = number_to_location(input);
+    let ans1 = Ok(location.manhattan(Point::ORIGIN).to_string());
 
     // Part 2: Iterate throughout the spiral memory
     let ans2;
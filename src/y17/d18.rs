@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+
+use anyhow::{bail, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+use crate::y17::duet::{self, Instruction, Operand, Program, Status};
+
+/// Runs the program alone, where `snd` plays a sound
+/// and `rcv` recovers the last sound played if its register is not zero.
+/// Returns the first sound that is recovered.
+fn first_recovered(instructions: &[Instruction]) -> Result<i64> {
+    let mut program = Program::new(instructions);
+    let mut last_sound = None;
+    // Nothing is received, as `rcv` is never run
+    let mut inbox = VecDeque::new();
+    loop {
+        if let Some(Instruction::Rcv(register)) = program.current() {
+            match last_sound {
+                Some(sound)
+                    if program.value(Operand::Register(register)) != 0 =>
+                {
+                    return Ok(sound)
+                }
+                _ => {
+                    program.skip();
+                    continue;
+                }
+            }
+        }
+        match program.step(&mut inbox)? {
+            Status::Sent(sound) => last_sound = Some(sound),
+            Status::Halted => bail!("program halted without recovering"),
+            _ => {}
+        }
+    }
+}
+
+/// Runs two copies of the program, with register p set to their ids,
+/// and where each one receives what the other sends.
+/// Returns how many values program 1 sent before both are stuck.
+fn sent_by_1(instructions: &[Instruction]) -> Result<usize> {
+    let mut programs = [0, 1].map(|id| {
+        let mut program = Program::new(instructions);
+        program.set_register('p', id);
+        program
+    });
+    let mut inboxes = [VecDeque::new(), VecDeque::new()];
+    let mut sent = 0;
+    loop {
+        let mut ran = false;
+        for id in 0..2 {
+            loop {
+                match programs[id].step(&mut inboxes[id])? {
+                    Status::Ran => {}
+                    Status::Sent(value) => {
+                        inboxes[1 - id].push_back(value);
+                        sent += id;
+                    }
+                    Status::Blocked | Status::Halted => break,
+                }
+                ran = true;
+            }
+        }
+        if !ran {
+            return Ok(sent);
+        }
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Instruction>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Instruction>> {
+        duet::parse(&lines)
+    }
+
+    /// Part 1: Recover a sound
+    fn part1(instructions: &Vec<Instruction>) -> Result<Answer> {
+        Ok(first_recovered(instructions)?.into())
+    }
+
+    /// Part 2: Run two programs that send values to each other
+    fn part2(instructions: &Vec<Instruction>) -> Result<Answer> {
+        Ok(sent_by_1(instructions)?.into())
+    }
+}
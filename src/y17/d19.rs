@@ -0,0 +1,86 @@
+use anyhow::{Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::geom::{Direction, Point};
+use crate::util::grid::Grid;
+use crate::util::solution::Solution;
+
+pub struct Diagram {
+    tiles: Grid<char>,
+}
+
+impl Diagram {
+    fn new(lines: &[String]) -> Result<Diagram> {
+        // Trailing spaces may have been trimmed, so lines are padded
+        let width = lines.iter().map(|line| line.chars().count()).max();
+        let width = width.context("diagram is empty")?;
+        let lines = lines
+            .iter()
+            .map(|line| format!("{:width$}", line, width = width))
+            .collect::<Vec<_>>();
+        let tiles = Grid::parse(&lines)?;
+        Ok(Diagram { tiles })
+    }
+
+    fn tile(&self, pos: Point) -> char {
+        let size = (self.tiles.width(), self.tiles.height());
+        match pos.to_grid_pos(size) {
+            Some(pos) => self.tiles[pos],
+            None => ' ',
+        }
+    }
+
+    /// Follows the path from the top, turning only at corners.
+    /// Returns the letters seen along the way, and the number of steps taken.
+    fn follow(&self) -> Result<(String, usize)> {
+        let x = self
+            .tiles
+            .rows()
+            .next()
+            .unwrap()
+            .iter()
+            .position(|c| *c == '|')
+            .context("the path does not start at the top")?;
+        let mut pos = Point::new(x as i64, 0);
+        let mut direction = Direction::South;
+        let mut letters = String::new();
+        let mut steps = 0;
+        while self.tile(pos) != ' ' {
+            match self.tile(pos) {
+                '+' => {
+                    direction = [direction.turn_left(), direction.turn_right()]
+                        .into_iter()
+                        .find(|d| self.tile(pos + d.delta()) != ' ')
+                        .context("the path ends at a corner")?;
+                }
+                c if c.is_ascii_alphabetic() => letters.push(c),
+                _ => {}
+            }
+            pos += direction.delta();
+            steps += 1;
+        }
+        Ok((letters, steps))
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Diagram;
+
+    fn parse(lines: Vec<String>) -> Result<Diagram> {
+        Diagram::new(&lines)
+    }
+
+    /// Part 1: Letters along the path
+    fn part1(diagram: &Diagram) -> Result<Answer> {
+        let (letters, _) = diagram.follow()?;
+        Ok(letters.into())
+    }
+
+    /// Part 2: Length of the path
+    fn part2(diagram: &Diagram) -> Result<Answer> {
+        let (_, steps) = diagram.follow()?;
+        Ok(steps.into())
+    }
+}
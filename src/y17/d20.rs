@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::geom::Point3;
+use crate::util::solution::Solution;
+
+/// Coefficients `[a, b, c]` of a polynomial `a*t^2 + b*t + c`
+type Quadratic = [i64; 3];
+
+fn axes(point: Point3) -> [i64; 3] {
+    [point.x, point.y, point.z]
+}
+
+fn evaluate([a, b, c]: Quadratic, t: i64) -> i64 {
+    (a * t + b) * t + c
+}
+
+/// Ticks at which the polynomial is zero,
+/// or None if it is zero at every tick.
+fn roots([a, b, c]: Quadratic) -> Option<Vec<i64>> {
+    let candidates = match (a, b) {
+        (0, 0) if c == 0 => return None,
+        (0, 0) => vec![],
+        (0, _) => vec![-c / b],
+        _ => {
+            let discriminant = b * b - 4 * a * c;
+            if discriminant < 0 {
+                return Some(vec![]);
+            }
+            let root = (discriminant as f64).sqrt().round() as i64;
+            vec![(-b - root) / (2 * a), (-b + root) / (2 * a)]
+        }
+    };
+    // Division rounds, so candidates are checked
+    let roots = candidates
+        .into_iter()
+        .filter(|t| *t >= 0 && evaluate([a, b, c], *t) == 0)
+        .collect();
+    Some(roots)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Particle {
+    position: Point3,
+    velocity: Point3,
+    acceleration: Point3,
+}
+
+impl Particle {
+    fn new(input: &str) -> Result<Particle> {
+        let vector = |input: &str| {
+            let (x, y, z) = sscanf!(input, "{},{},{}", i64, i64, i64).ok()?;
+            Some(Point3::new(x, y, z))
+        };
+        let stripped = input.replace(' ', "");
+        let vectors = sscanf!(&stripped, "p=<{}>,v=<{}>,a=<{}>", str, str, str)
+            .ok()
+            .and_then(|(p, v, a)| Some((vector(p)?, vector(v)?, vector(a)?)));
+        let (position, velocity, acceleration) =
+            vectors.with_context(|| format!("invalid particle `{}`", input))?;
+        Ok(Particle { position, velocity, acceleration })
+    }
+
+    /// Twice the position along each axis at tick t, as polynomials of t.
+    /// Velocity increases before position does, so after t ticks,
+    /// position is `p + v*t + a*t*(t+1)/2`.
+    fn trajectory(&self) -> [Quadratic; 3] {
+        let (p, v, a) =
+            (axes(self.position), axes(self.velocity), axes(self.acceleration));
+        [0, 1, 2].map(|i| [a[i], 2 * v[i] + a[i], 2 * p[i]])
+    }
+
+    /// Twice the distance from the origin in the long run, as a polynomial of t.
+    /// Each axis eventually stays on one side of the origin,
+    /// on the side that its leading nonzero coefficient is on.
+    fn long_run_distance(&self) -> Quadratic {
+        let mut distance = [0; 3];
+        for axis in self.trajectory() {
+            let sign = axis.iter().find(|c| **c != 0).map_or(0, |c| c.signum());
+            for (d, c) in distance.iter_mut().zip(axis) {
+                *d += sign * c;
+            }
+        }
+        distance
+    }
+
+    /// First tick at which two particles are at the same position
+    fn collides(&self, other: &Particle) -> Option<i64> {
+        let difference = self
+            .trajectory()
+            .into_iter()
+            .zip(other.trajectory())
+            .map(|(a, b)| [a[0] - b[0], a[1] - b[1], a[2] - b[2]])
+            .collect::<Vec<_>>();
+        let candidates = match difference.iter().find_map(|axis| roots(*axis)) {
+            Some(roots) => roots,
+            // The particles start at the same position, and never separate
+            None => vec![0],
+        };
+        candidates
+            .into_iter()
+            .filter(|t| difference.iter().all(|axis| evaluate(*axis, *t) == 0))
+            .min()
+    }
+}
+
+/// Removes particles as they collide.
+/// Returns the particles that never collide.
+fn survivors(particles: &[Particle]) -> HashSet<usize> {
+    let collisions = (0..particles.len())
+        .tuple_combinations()
+        .filter_map(|(i, j)| {
+            let tick = particles[i].collides(&particles[j])?;
+            Some((tick, i, j))
+        })
+        .sorted()
+        .collect::<Vec<_>>();
+    let mut survivors = (0..particles.len()).collect::<HashSet<_>>();
+    for (_, group) in &collisions.into_iter().group_by(|(tick, _, _)| *tick) {
+        // Particles that collide at the same tick are removed together
+        let collided = group
+            .filter(|(_, i, j)| survivors.contains(i) && survivors.contains(j))
+            .flat_map(|(_, i, j)| [i, j])
+            .collect::<Vec<_>>();
+        for i in collided {
+            survivors.remove(&i);
+        }
+    }
+    survivors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roots() {
+        assert_eq!(roots([1, -5, 6]), Some(vec![2, 3]));
+        assert_eq!(roots([1, 1, 6]), Some(vec![]));
+        assert_eq!(roots([0, 2, -6]), Some(vec![3]));
+        assert_eq!(roots([0, 2, 6]), Some(vec![]));
+        assert_eq!(roots([2, 0, -1]), Some(vec![]));
+        assert_eq!(roots([0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_collides() {
+        let particle = |s: &str| Particle::new(s).unwrap();
+        let a = particle("p=<0,0,0>, v=<1,0,0>, a=<0,1,0>");
+        let b = particle("p=<2,1,0>, v=<0,0,0>, a=<0,0,0>");
+        // a is at (1,1,0) and then (2,3,0)
+        assert_eq!(a.collides(&b), None);
+        let b = particle("p=<2,3,0>, v=<0,0,0>, a=<0,0,0>");
+        assert_eq!(a.collides(&b), Some(2));
+        assert_eq!(a.collides(&a), Some(0));
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Particle>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Particle>> {
+        lines.iter().map(|line| Particle::new(line)).collect()
+    }
+
+    /// Part 1: Particle that stays closest to the origin in the long run
+    fn part1(particles: &Vec<Particle>) -> Result<Answer> {
+        let closest = (0..particles.len())
+            .min_by_key(|i| particles[*i].long_run_distance())
+            .context("there are no particles")?;
+        Ok(closest.into())
+    }
+
+    /// Part 2: Particles left after collisions
+    fn part2(particles: &Vec<Particle>) -> Result<Answer> {
+        Ok(survivors(particles).len().into())
+    }
+}
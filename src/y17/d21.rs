@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::grid::Grid;
+use crate::util::solution::Solution;
+
+type Pattern = Grid<bool>;
+
+/// Parses a pattern written like `.#./..#/###`
+fn pattern(input: &str) -> Result<Pattern> {
+    let rows = input.split('/').map(|row| row.to_string()).collect::<Vec<_>>();
+    let pattern = Grid::parse_with(&rows, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if pattern.width() != pattern.height() {
+        bail!("pattern `{}` is not square", input);
+    }
+    Ok(pattern)
+}
+
+/// The eight ways a pattern can be rotated and flipped
+fn orientations(pattern: &Pattern) -> Vec<Pattern> {
+    let mut result = vec![];
+    let mut pattern = pattern.clone();
+    for _ in 0..4 {
+        result.push(pattern.flip_horizontal());
+        pattern = pattern.rotate_clockwise();
+        result.push(pattern.clone());
+    }
+    result
+}
+
+pub struct Rulebook {
+    /// Enhancements of every orientation of every input pattern
+    rules: HashMap<Pattern, Pattern>,
+}
+
+impl Rulebook {
+    fn new(lines: &[String]) -> Result<Rulebook> {
+        let mut rules = HashMap::new();
+        for line in lines {
+            let (from, to) = line
+                .split_once(" => ")
+                .with_context(|| format!("invalid rule `{}`", line))?;
+            let (from, to) = (pattern(from)?, pattern(to)?);
+            if ![(2, 3), (3, 4)].contains(&(from.width(), to.width())) {
+                bail!("rule `{}` has the wrong sizes", line);
+            }
+            for from in orientations(&from) {
+                rules.insert(from, to.clone());
+            }
+        }
+        Ok(Rulebook { rules })
+    }
+
+    /// Breaks the image into 2x2 or 3x3 squares, and enhances each one.
+    fn enhance(&self, image: &Pattern) -> Result<Pattern> {
+        let size = image.width();
+        let from = if size.is_multiple_of(2) { 2 } else { 3 };
+        let to = from + 1;
+        let squares = size / from;
+        let mut enhanced = Vec::with_capacity(squares);
+        for sy in 0..squares {
+            let mut row = Vec::with_capacity(squares);
+            for sx in 0..squares {
+                let square = Grid::from_fn(from, from, |(x, y)| {
+                    image[(sx * from + x, sy * from + y)]
+                });
+                let rule = self
+                    .rules
+                    .get(&square)
+                    .context("no rule matches a square")?;
+                row.push(rule);
+            }
+            enhanced.push(row);
+        }
+        let image = Grid::from_fn(squares * to, squares * to, |(x, y)| {
+            enhanced[y / to][x / to][(x % to, y % to)]
+        });
+        Ok(image)
+    }
+
+    /// Number of pixels that are on after some iterations
+    fn pixels_on(&self, iterations: usize) -> Result<usize> {
+        let mut image = pattern(".#./..#/###").unwrap();
+        for _ in 0..iterations {
+            image = self.enhance(&image)?;
+        }
+        Ok(image.iter().filter(|(_, on)| **on).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pixels_on() {
+        let lines =
+            ["../.# => ##./#../...", ".#./..#/### => #..#/..../..../#..#"]
+                .map(|s| s.to_string());
+        let rulebook = Rulebook::new(&lines).unwrap();
+        assert_eq!(rulebook.pixels_on(1).unwrap(), 4);
+        assert_eq!(rulebook.pixels_on(2).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_orientations() {
+        let glider = pattern(".#./..#/###").unwrap();
+        let orientations = orientations(&glider);
+        assert!(orientations.contains(&pattern(".#./#../###").unwrap()));
+        assert!(orientations.contains(&pattern("#../#.#/##.").unwrap()));
+        assert!(orientations.contains(&pattern("###/..#/.#.").unwrap()));
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Rulebook;
+
+    fn parse(lines: Vec<String>) -> Result<Rulebook> {
+        Rulebook::new(&lines)
+    }
+
+    /// Part 1: After 5 iterations
+    fn part1(rulebook: &Rulebook) -> Result<Answer> {
+        Ok(rulebook.pixels_on(5)?.into())
+    }
+
+    /// Part 2: After 18 iterations
+    fn part2(rulebook: &Rulebook) -> Result<Answer> {
+        Ok(rulebook.pixels_on(18)?.into())
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+
+use crate::util::answer::Answer;
+use crate::util::geom::{Direction, Point};
+use crate::util::grid::Grid;
+use crate::util::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Node {
+    Clean,
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+impl Node {
+    /// What the virus does to the node, if it is evolved or not
+    fn next(&self, evolved: bool) -> Node {
+        match (self, evolved) {
+            (Node::Clean, false) => Node::Infected,
+            (Node::Infected, false) => Node::Clean,
+            (Node::Clean, true) => Node::Weakened,
+            (Node::Weakened, true) => Node::Infected,
+            (Node::Infected, true) => Node::Flagged,
+            (Node::Flagged, true) | (_, false) => Node::Clean,
+        }
+    }
+
+    /// Where the virus carrier turns to on the node
+    fn turn(&self, direction: Direction) -> Direction {
+        match self {
+            Node::Clean => direction.turn_left(),
+            Node::Weakened => direction,
+            Node::Infected => direction.turn_right(),
+            Node::Flagged => direction.rev(),
+        }
+    }
+}
+
+pub struct Cluster {
+    /// Nodes that are not clean
+    nodes: HashMap<Point, Node>,
+    /// The middle of the map, where the virus carrier starts
+    middle: Point,
+}
+
+impl Cluster {
+    fn new(lines: &[String]) -> Result<Cluster> {
+        let map = Grid::parse_with(lines, |c| match c {
+            '#' => Some(Node::Infected),
+            '.' => Some(Node::Clean),
+            _ => None,
+        })?;
+        if map.width().is_multiple_of(2) || map.height().is_multiple_of(2) {
+            bail!("map has no middle");
+        }
+        let nodes = map
+            .iter()
+            .filter(|(_, node)| **node != Node::Clean)
+            .map(|(pos, node)| (Point::from_grid_pos(pos), *node))
+            .collect();
+        let middle = Point::from_grid_pos((map.width() / 2, map.height() / 2));
+        Ok(Cluster { nodes, middle })
+    }
+
+    /// Number of bursts that cause a node to become infected
+    fn infections(&self, bursts: usize, evolved: bool) -> usize {
+        let mut nodes = self.nodes.clone();
+        let mut pos = self.middle;
+        let mut direction = Direction::North;
+        let mut infections = 0;
+        for _ in 0..bursts {
+            let node = nodes.entry(pos).or_insert(Node::Clean);
+            direction = node.turn(direction);
+            *node = node.next(evolved);
+            if *node == Node::Infected {
+                infections += 1;
+            }
+            pos += direction.delta();
+        }
+        infections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infections() {
+        let lines = ["..#", "#..", "..."].map(|s| s.to_string());
+        let cluster = Cluster::new(&lines).unwrap();
+        assert_eq!(cluster.infections(7, false), 5);
+        assert_eq!(cluster.infections(70, false), 41);
+        assert_eq!(cluster.infections(100, true), 26);
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Cluster;
+
+    fn parse(lines: Vec<String>) -> Result<Cluster> {
+        Cluster::new(&lines)
+    }
+
+    /// Part 1: Clean nodes become infected, and infected nodes become clean
+    fn part1(cluster: &Cluster) -> Result<Answer> {
+        Ok(cluster.infections(10_000, false).into())
+    }
+
+    /// Part 2: Nodes are weakened before they are infected,
+    /// and flagged before they are cleaned
+    fn part2(cluster: &Cluster) -> Result<Answer> {
+        Ok(cluster.infections(10_000_000, true).into())
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::VecDeque;
+
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+use crate::y17::duet::{self, Instruction, Operand, Program, Status};
+
+/// Register b, which holds the number being checked
+const B: usize = 1;
+/// Register f, which is set once the setup is done
+const F: usize = 5;
+
+/// Runs the program until it halts.
+/// Returns how many times `mul` was run.
+fn count_mul(instructions: &[Instruction]) -> Result<usize> {
+    let mut program = Program::new(instructions);
+    let mut count = 0;
+    loop {
+        if let Some(Instruction::Mul(..)) = program.current() {
+            count += 1;
+        }
+        match program.step(&mut VecDeque::new())? {
+            Status::Ran => {}
+            Status::Halted => return Ok(count),
+            _ => bail!("the coprocessor cannot communicate"),
+        }
+    }
+}
+
+fn is_prime(n: i64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+/// With register a set to 1, the program counts the composite numbers
+/// from b to c, stepping by some amount, which takes too long to run.
+/// Instead, runs the setup to find b and c, and counts them directly.
+fn count_composites(instructions: &[Instruction]) -> Result<usize> {
+    let setup_end = instructions
+        .iter()
+        .position(|i| *i == Instruction::Set(F, Operand::Number(1)))
+        .context("could not find the end of the setup")?;
+    let step = instructions
+        .iter()
+        .rev()
+        .find_map(|i| match i {
+            Instruction::Sub(B, Operand::Number(n)) if *n < 0 => Some(-n),
+            _ => None,
+        })
+        .context("could not find how b is stepped")?;
+    let mut program = Program::new(instructions);
+    program.set_register('a', 1);
+    while program.pointer() != Some(setup_end) {
+        match program.step(&mut VecDeque::new())? {
+            Status::Ran => {}
+            _ => bail!("the setup did not finish"),
+        }
+    }
+    let (b, c) = (program.register('b'), program.register('c'));
+    if b > c || (c - b) % step != 0 {
+        bail!("cannot step from {} to {} by {}", b, c, step);
+    }
+    let count =
+        (b..=c).step_by(step as usize).filter(|n| !is_prime(*n)).count();
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_mul() {
+        let lines = ["set a 3", "mul b a", "sub a 1", "jnz a -2"]
+            .map(|s| s.to_string());
+        let instructions = duet::parse(&lines).unwrap();
+        assert_eq!(count_mul(&instructions).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let primes = (0..30).filter(|n| is_prime(*n)).collect::<Vec<_>>();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<Instruction>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Instruction>> {
+        duet::parse(&lines)
+    }
+
+    /// Part 1: Count how many times `mul` is run
+    fn part1(instructions: &Vec<Instruction>) -> Result<Answer> {
+        Ok(count_mul(instructions)?.into())
+    }
+
+    /// Part 2: Find the final value of register h, with register a set to 1
+    fn part2(instructions: &Vec<Instruction>) -> Result<Answer> {
+        Ok(count_composites(instructions)?.into())
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::{bail, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+/// A component with a port on each end, by their number of pins
+type Component = (u32, u32);
+
+/// Strength and length of a bridge
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Bridge {
+    strength: u32,
+    length: u32,
+}
+
+/// Builds every bridge that continues from a port with some pins,
+/// using the components that are not used yet.
+/// Calls visit on each bridge.
+fn build<F>(
+    components: &[Component],
+    used: &mut [bool],
+    pins: u32,
+    bridge: Bridge,
+    visit: &mut F,
+) where
+    F: FnMut(Bridge),
+{
+    visit(bridge);
+    for (ii, &(a, b)) in components.iter().enumerate() {
+        if used[ii] || (a != pins && b != pins) {
+            continue;
+        }
+        let other = if a == pins { b } else { a };
+        let next = Bridge {
+            strength: bridge.strength + a + b,
+            length: bridge.length + 1,
+        };
+        used[ii] = true;
+        build(components, used, other, next, visit);
+        used[ii] = false;
+    }
+}
+
+/// Builds every bridge from the zero-pin port, and returns the best one
+/// according to key
+fn best_bridge<K, F>(components: &[Component], key: F) -> Bridge
+where
+    K: Ord,
+    F: Fn(&Bridge) -> K,
+{
+    let mut best = Bridge::default();
+    let mut used = vec![false; components.len()];
+    build(components, &mut used, 0, best, &mut |bridge| {
+        if key(&bridge) > key(&best) {
+            best = bridge;
+        }
+    });
+    best
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Component>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Component>> {
+        let mut components = vec![];
+        for line in &lines {
+            match sscanf!(line, "{}/{}", u32, u32) {
+                Ok(component) => components.push(component),
+                Err(_) => bail!("invalid component `{}`", line),
+            }
+        }
+        Ok(components)
+    }
+
+    /// Part 1: Strongest bridge
+    fn part1(components: &Vec<Component>) -> Result<Answer> {
+        let best = best_bridge(components, |bridge| bridge.strength);
+        Ok(best.strength.into())
+    }
+
+    /// Part 2: Strongest of the longest bridges
+    fn part2(components: &Vec<Component>) -> Result<Answer> {
+        let best =
+            best_bridge(components, |bridge| (bridge.length, bridge.strength));
+        Ok(best.strength.into())
+    }
+}
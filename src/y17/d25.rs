@@ -0,0 +1,151 @@
+use std::collections::VecDeque;
+
+use anyhow::{bail, Context, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+use crate::util::vectors::group;
+
+/// What a state does when the cursor is over some value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Action {
+    write: bool,
+    /// -1 to move left, 1 to move right
+    offset: i64,
+    next: usize,
+}
+
+/// States are named by a single uppercase letter
+fn state(input: char) -> Result<usize> {
+    match input {
+        'A'..='Z' => Ok(input as usize - 'A' as usize),
+        _ => bail!("invalid state `{}`", input),
+    }
+}
+
+impl Action {
+    /// Parses the three lines of an action
+    fn new(lines: &[String]) -> Result<Action> {
+        let [write, offset, next] = lines else {
+            bail!("expected 3 lines for an action, got {}", lines.len());
+        };
+        let write = match sscanf!(write, "- Write the value {}.", u8) {
+            Ok(0) => false,
+            Ok(1) => true,
+            _ => bail!("invalid write `{}`", write),
+        };
+        let offset = match sscanf!(offset, "- Move one slot to the {}.", str) {
+            Ok("left") => -1,
+            Ok("right") => 1,
+            _ => bail!("invalid move `{}`", offset),
+        };
+        let next = sscanf!(next, "- Continue with state {}.", char)
+            .ok()
+            .with_context(|| format!("invalid next state `{}`", next))?;
+        Ok(Action { write, offset, next: state(next)? })
+    }
+}
+
+pub struct Blueprint {
+    start: usize,
+    steps: usize,
+    /// Actions of each state, when the value is 0 and when it is 1
+    states: Vec<[Action; 2]>,
+}
+
+impl Blueprint {
+    fn new(lines: Vec<String>) -> Result<Blueprint> {
+        let lines = lines.iter().map(|line| line.trim().to_string()).collect();
+        let groups = group(lines);
+        let Some((header, states)) = groups.split_first() else {
+            bail!("blueprint is empty");
+        };
+        let [start, steps] = &header[..] else {
+            bail!("expected 2 lines for the header, got {}", header.len());
+        };
+        let start = sscanf!(start, "Begin in state {}.", char)
+            .ok()
+            .with_context(|| format!("invalid start `{}`", start))?;
+        let steps = sscanf!(
+            steps,
+            "Perform a diagnostic checksum after {} steps.",
+            usize
+        )
+        .ok()
+        .with_context(|| format!("invalid steps `{}`", steps))?;
+
+        let mut actions = vec![];
+        for (ii, lines) in states.iter().enumerate() {
+            let expected = (b'A' + ii as u8) as char;
+            let [name, if0, _, _, _, if1, _, _, _] = &lines[..] else {
+                bail!("expected 9 lines for state {}", expected);
+            };
+            match sscanf!(name, "In state {}:", char) {
+                Ok(name) if name == expected => {}
+                _ => bail!("expected state {} to be defined next", expected),
+            }
+            if if0 != "If the current value is 0:"
+                || if1 != "If the current value is 1:"
+            {
+                bail!("invalid conditions for state {}", expected);
+            }
+            actions
+                .push([Action::new(&lines[2..5])?, Action::new(&lines[6..])?]);
+        }
+        let blueprint =
+            Blueprint { start: state(start)?, steps, states: actions };
+        let defined = blueprint.states.len();
+        let nexts = blueprint.states.iter().flatten().map(|action| action.next);
+        if let Some(next) =
+            nexts.chain([blueprint.start]).find(|n| *n >= defined)
+        {
+            bail!("state {} is not defined", (b'A' + next as u8) as char);
+        }
+        Ok(blueprint)
+    }
+
+    /// Runs the machine from an empty tape.
+    /// Returns the number of ones on the tape at the end.
+    fn checksum(&self) -> usize {
+        let mut tape = VecDeque::from([false]);
+        let mut cursor = 0;
+        let mut state = self.start;
+        for _ in 0..self.steps {
+            let action = self.states[state][tape[cursor] as usize];
+            tape[cursor] = action.write;
+            state = action.next;
+            match (cursor, action.offset) {
+                (0, -1) => tape.push_front(false),
+                (_, -1) => cursor -= 1,
+                _ => {
+                    cursor += 1;
+                    if cursor == tape.len() {
+                        tape.push_back(false);
+                    }
+                }
+            }
+        }
+        tape.iter().filter(|value| **value).count()
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Blueprint;
+
+    fn parse(lines: Vec<String>) -> Result<Blueprint> {
+        Blueprint::new(lines)
+    }
+
+    /// Part 1: Run the diagnostic checksum
+    fn part1(blueprint: &Blueprint) -> Result<Answer> {
+        Ok(blueprint.checksum().into())
+    }
+
+    /// Part 2: There is no part 2
+    fn part2(_: &Blueprint) -> Result<Answer> {
+        Ok("Merry Christmas!".into())
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::VecDeque;
+
+use anyhow::{bail, Context, Result};
+
+/// Registers are named by a single lowercase letter
+const REGISTERS: usize = 26;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(usize),
+    Number(i64),
+}
+
+/// An instruction of the assembly in [y17-d18](https://adventofcode.com/2017/day/18),
+/// which the coprocessor in [y17-d23](https://adventofcode.com/2017/day/23) also runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Snd(Operand),
+    Set(usize, Operand),
+    Add(usize, Operand),
+    Sub(usize, Operand),
+    Mul(usize, Operand),
+    Mod(usize, Operand),
+    Rcv(usize),
+    /// Jumps if the first Operand is greater than zero
+    Jgz(Operand, Operand),
+    /// Jumps if the first Operand is not zero
+    Jnz(Operand, Operand),
+}
+
+fn register(input: &str) -> Result<usize> {
+    match input.as_bytes() {
+        [c @ b'a'..=b'z'] => Ok((c - b'a') as usize),
+        _ => bail!("invalid register `{}`", input),
+    }
+}
+
+impl Operand {
+    fn new(input: &str) -> Result<Operand> {
+        match input.parse() {
+            Ok(n) => Ok(Operand::Number(n)),
+            Err(_) => Ok(Operand::Register(register(input)?)),
+        }
+    }
+}
+
+impl Instruction {
+    pub fn new(input: &str) -> Result<Instruction> {
+        let words = input.split(' ').collect::<Vec<_>>();
+        let instruction = match words[..] {
+            ["snd", a] => Instruction::Snd(Operand::new(a)?),
+            ["rcv", a] => Instruction::Rcv(register(a)?),
+            ["jgz", a, b] => {
+                Instruction::Jgz(Operand::new(a)?, Operand::new(b)?)
+            }
+            ["jnz", a, b] => {
+                Instruction::Jnz(Operand::new(a)?, Operand::new(b)?)
+            }
+            [op, a, b] => {
+                let (a, b) = (register(a)?, Operand::new(b)?);
+                match op {
+                    "set" => Instruction::Set(a, b),
+                    "add" => Instruction::Add(a, b),
+                    "sub" => Instruction::Sub(a, b),
+                    "mul" => Instruction::Mul(a, b),
+                    "mod" => Instruction::Mod(a, b),
+                    _ => bail!("invalid operation `{}`", op),
+                }
+            }
+            _ => bail!("invalid instruction `{}`", input),
+        };
+        Ok(instruction)
+    }
+}
+
+/// Parses each line into an Instruction
+pub fn parse(lines: &[String]) -> Result<Vec<Instruction>> {
+    lines
+        .iter()
+        .map(|line| {
+            Instruction::new(line)
+                .with_context(|| format!("could not parse `{}`", line))
+        })
+        .collect()
+}
+
+/// What a Program did when asked to step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Ran an instruction that did not send anything
+    Ran,
+    /// Ran `snd`, sending a value
+    Sent(i64),
+    /// Is waiting on `rcv` for a value to arrive
+    Blocked,
+    /// Jumped outside of the instructions
+    Halted,
+}
+
+/// A program running some instructions, with its own registers
+#[derive(Debug, Clone)]
+pub struct Program<'a> {
+    instructions: &'a [Instruction],
+    registers: [i64; REGISTERS],
+    /// Index of the next instruction, which may be outside of the instructions
+    pointer: i64,
+}
+
+impl<'a> Program<'a> {
+    /// Starts at the first instruction, with every register at zero
+    pub fn new(instructions: &'a [Instruction]) -> Program<'a> {
+        Program { instructions, registers: [0; REGISTERS], pointer: 0 }
+    }
+
+    pub fn register(&self, name: char) -> i64 {
+        self.registers[(name as u8 - b'a') as usize]
+    }
+
+    pub fn set_register(&mut self, name: char, value: i64) {
+        self.registers[(name as u8 - b'a') as usize] = value;
+    }
+
+    /// Index of the next instruction, if the program has not halted
+    pub fn pointer(&self) -> Option<usize> {
+        let pointer = usize::try_from(self.pointer).ok()?;
+        (pointer < self.instructions.len()).then_some(pointer)
+    }
+
+    /// The next instruction, if the program has not halted
+    pub fn current(&self) -> Option<Instruction> {
+        Some(self.instructions[self.pointer()?])
+    }
+
+    /// Moves on to the next instruction without running the current one
+    pub fn skip(&mut self) {
+        self.pointer += 1;
+    }
+
+    pub fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(r) => self.registers[r],
+            Operand::Number(n) => n,
+        }
+    }
+
+    /// Runs the next instruction, where `rcv` takes values from inbox.
+    /// Returns an error if the program takes a modulo by zero.
+    pub fn step(&mut self, inbox: &mut VecDeque<i64>) -> Result<Status> {
+        let Some(instruction) = self.current() else {
+            return Ok(Status::Halted);
+        };
+        let mut status = Status::Ran;
+        let mut offset = 1;
+        match instruction {
+            Instruction::Snd(a) => status = Status::Sent(self.value(a)),
+            Instruction::Set(a, b) => self.registers[a] = self.value(b),
+            Instruction::Add(a, b) => self.registers[a] += self.value(b),
+            Instruction::Sub(a, b) => self.registers[a] -= self.value(b),
+            Instruction::Mul(a, b) => self.registers[a] *= self.value(b),
+            Instruction::Mod(a, b) => match self.value(b) {
+                0 => bail!("modulo by zero at instruction {}", self.pointer),
+                b => self.registers[a] = self.registers[a].rem_euclid(b),
+            },
+            Instruction::Rcv(a) => match inbox.pop_front() {
+                Some(value) => self.registers[a] = value,
+                None => return Ok(Status::Blocked),
+            },
+            Instruction::Jgz(a, b) if self.value(a) > 0 => {
+                offset = self.value(b)
+            }
+            Instruction::Jnz(a, b) if self.value(a) != 0 => {
+                offset = self.value(b)
+            }
+            Instruction::Jgz(..) | Instruction::Jnz(..) => {}
+        }
+        self.pointer += offset;
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instructions(lines: &[&str]) -> Vec<Instruction> {
+        let lines = lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        parse(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_step() {
+        let instructions = instructions(&[
+            "set a 7", "mod a -3", "snd a", "rcv b", "jnz a -9",
+        ]);
+        let mut program = Program::new(&instructions);
+        let mut inbox = VecDeque::new();
+        assert_eq!(program.step(&mut inbox).unwrap(), Status::Ran);
+        assert_eq!(program.step(&mut inbox).unwrap(), Status::Ran);
+        assert_eq!(program.register('a'), 1);
+        assert_eq!(program.step(&mut inbox).unwrap(), Status::Sent(1));
+        assert_eq!(program.step(&mut inbox).unwrap(), Status::Blocked);
+        assert_eq!(program.pointer(), Some(3));
+        inbox.push_back(5);
+        assert_eq!(program.step(&mut inbox).unwrap(), Status::Ran);
+        assert_eq!(program.register('b'), 5);
+        assert_eq!(program.step(&mut inbox).unwrap(), Status::Ran);
+        assert_eq!(program.current(), None);
+        assert_eq!(program.step(&mut inbox).unwrap(), Status::Halted);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Instruction::new("jgz 1 p").unwrap(),
+            Instruction::Jgz(Operand::Number(1), Operand::Register(15))
+        );
+        assert!(Instruction::new("set 1 a").is_err());
+        assert!(Instruction::new("rcv").is_err());
+    }
+}
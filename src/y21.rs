@@ -1,4 +1,4 @@
-use anyhow::Result;
+use crate::util::Solver;
 
 mod d01;
 mod d02;
@@ -17,34 +17,40 @@ mod d14;
 mod d15;
 mod d16;
 mod d17;
+mod d18;
+mod d19;
 mod d20;
 mod d21;
 mod d22;
+mod d23;
+mod d24;
+mod d25;
 
-pub fn solver(
-    day: u8,
-) -> fn(Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    match day {
-        1 => d01::solve,
-        2 => d02::solve,
-        3 => d03::solve,
-        4 => d04::solve,
-        5 => d05::solve,
-        6 => d06::solve,
-        7 => d07::solve,
-        8 => d08::solve,
-        9 => d09::solve,
-        10 => d10::solve,
-        11 => d11::solve,
-        12 => d12::solve,
-        13 => d13::solve,
-        14 => d14::solve,
-        15 => d15::solve,
-        16 => d16::solve,
-        17 => d17::solve,
-        20 => d20::solve,
-        21 => d21::solve,
-        22 => d22::solve,
-        _ => todo!("day {}", day),
-    }
-}
+/// Solvers for every implemented day, in increasing order of day.
+pub const SOLVERS: &[(u8, Solver)] = &[
+    (1, Solver::Whole(d01::solve)),
+    (2, Solver::Whole(d02::solve)),
+    (3, Solver::Whole(d03::solve)),
+    (4, Solver::Whole(d04::solve)),
+    (5, Solver::Whole(d05::solve)),
+    (6, Solver::Whole(d06::solve)),
+    (7, Solver::Whole(d07::solve)),
+    (8, Solver::Whole(d08::solve)),
+    (9, Solver::Whole(d09::solve)),
+    (10, Solver::Whole(d10::solve)),
+    (11, Solver::Whole(d11::solve)),
+    (12, Solver::Whole(d12::solve)),
+    (13, Solver::staged::<d13::Day13>()),
+    (14, Solver::Whole(d14::solve)),
+    (15, Solver::Whole(d15::solve)),
+    (16, Solver::Whole(d16::solve)),
+    (17, Solver::Whole(d17::solve)),
+    (18, Solver::staged::<d18::Day18>()),
+    (19, Solver::staged::<d19::Day19>()),
+    (20, Solver::Whole(d20::solve)),
+    (21, Solver::Whole(d21::solve)),
+    (22, Solver::Whole(d22::solve)),
+    (23, Solver::staged::<d23::Day23>()),
+    (24, Solver::staged::<d24::Day24>()),
+    (25, Solver::staged::<d25::Day25>()),
+];
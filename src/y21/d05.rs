@@ -3,48 +3,32 @@ use num;
 use num::Integer;
 use std::fmt;
 
-const BOUND: usize = 1000;
-
-#[derive(Clone, Debug, PartialEq)]
-struct Point(usize, usize);
-
-impl Point {
-    /// new("816,14") -> Point(816,14)
-    fn new(input: &str) -> Result<Point> {
-        let split: Vec<&str> = input.split(',').collect();
-        if split.len() != 2 {
-            bail!("could not split by commas");
-        }
-
-        let c1 = split[0]
-            .parse()
-            .context(format!("could not parse {} as usize", split[0]))?;
-        if c1 >= BOUND {
-            bail!("x-coord should be less than {}, got {}", BOUND, c1);
-        }
+use crate::util::geom::Point;
 
-        let c2 = split[1]
-            .parse()
-            .context(format!("could not parse {} as usize", split[1]))?;
-        if c2 >= BOUND {
-            bail!("y-coord should be less than {}, got {}", BOUND, c2);
-        }
+const BOUND: usize = 1000;
 
-        Ok(Point(c1, c2))
+/// new("816,14") -> Point(816,14)
+fn new_point(input: &str) -> Result<Point> {
+    let split: Vec<&str> = input.split(',').collect();
+    if split.len() != 2 {
+        bail!("could not split by commas");
     }
 
-    /// (x,y).add(c,d) = (x+c,y+d)
-    fn add(&self, x_step: isize, y_step: isize) -> Point {
-        Point(
-            (self.0 as isize + x_step) as usize,
-            (self.1 as isize + y_step) as usize,
-        )
+    let c1 = split[0]
+        .parse()
+        .context(format!("could not parse {} as usize", split[0]))?;
+    if c1 >= BOUND {
+        bail!("x-coord should be less than {}, got {}", BOUND, c1);
     }
-}
-impl fmt::Display for Point {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({},{})", self.0, self.1)
+
+    let c2 = split[1]
+        .parse()
+        .context(format!("could not parse {} as usize", split[1]))?;
+    if c2 >= BOUND {
+        bail!("y-coord should be less than {}, got {}", BOUND, c2);
     }
+
+    Ok(Point::from_grid_pos((c1, c2)))
 }
 
 #[derive(Debug)]
@@ -58,16 +42,15 @@ impl Line {
             bail!("could not split points");
         }
 
-        let p1 =
-            Point::new(points[0]).context("could not parse first point")?;
+        let p1 = new_point(points[0]).context("could not parse first point")?;
         let p2 =
-            Point::new(points[1]).context("could not parse seconod popint")?;
+            new_point(points[1]).context("could not parse seconod popint")?;
         Ok(Line(p1, p2))
     }
 
     /// checks if the Line is parallel to the x- or y-axis.
     fn is_axial(&self) -> bool {
-        self.0 .0 == self.1 .0 || self.0 .1 == self.1 .1
+        self.0.x == self.1.x || self.0.y == self.1.y
     }
 }
 
@@ -94,26 +77,23 @@ impl Grid {
     }
 
     fn draw(&mut self, line: &Line) {
-        let p1 = &line.0;
-        let p2 = &line.1;
+        let (p1, p2) = (line.0, line.1);
 
         // The input's non-axial lines *should* be 45-deg ones.
         // However, let's just prepare for the worst.
         // This code will draw the Line onto the Grid
         // only on points with whole numbers.
         // Or whatever, it's difficult to explain via text.
-        let x_diff: isize = p2.0 as isize - p1.0 as isize;
-        let y_diff: isize = p2.1 as isize - p1.1 as isize;
-        let gcd: isize = x_diff.abs().gcd(&y_diff.abs());
-        let x_step = x_diff / gcd;
-        let y_step = y_diff / gcd;
-
-        let mut pt = p1.clone();
-        while &pt != p2 {
-            self.0[pt.1][pt.0] += 1;
-            pt = pt.add(x_step, y_step);
+        let diff = p2 - p1;
+        let gcd = diff.x.abs().gcd(&diff.y.abs());
+        let step = Point::new(diff.x / gcd, diff.y / gcd);
+
+        let mut pt = p1;
+        while pt != p2 {
+            self.0[pt.y as usize][pt.x as usize] += 1;
+            pt += step;
         }
-        self.0[pt.1][pt.0] += 1;
+        self.0[pt.y as usize][pt.x as usize] += 1;
     }
 
     fn count_intersections(&self) -> usize {
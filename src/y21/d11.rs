@@ -1,129 +1,61 @@
 use anyhow::{bail, Context, Result};
 use std::fmt;
 
+use crate::util::grid::Grid;
+
 struct Octopuses {
-    energy_levels: [[u8; 10]; 10],
+    energy_levels: Grid<u8>,
 }
 
 impl Octopuses {
     fn new(lines: Vec<String>) -> Result<Octopuses> {
-        if lines.len() != 10 {
+        let energy_levels =
+            Grid::parse_with(&lines, |ch| ch.to_digit(10).map(|d| d as u8))?;
+        if (energy_levels.width(), energy_levels.height()) != (10, 10) {
             bail!(
-                "expected lines to be of length 10, got {} instead",
-                lines.len()
+                "expected a 10x10 grid, got {}x{} instead",
+                energy_levels.width(),
+                energy_levels.height()
             )
         }
 
-        let mut energy_levels = [[0; 10]; 10];
-        for ii in 0..10 {
-            let line = lines.get(ii).unwrap();
-            if line.len() != 10 {
-                bail!(
-                    "expected line `{}` to be of length 10, got {} instead",
-                    line,
-                    line.len()
-                )
-            }
-            let line: Vec<char> = line.chars().collect();
-            for jj in 0..10 {
-                let ch = line[jj];
-                match ch.to_digit(10) {
-                    None => bail!(
-                        "could not parse character `{}` from line `{:?}`",
-                        ch,
-                        line
-                    ),
-                    Some(d) => energy_levels[ii][jj] = d as u8,
-                }
-            }
-        }
-
         Ok(Octopuses { energy_levels })
     }
 
     /// Returns how many octopuses have flashed
     fn next(&mut self) -> u32 {
         let mut flash_queue = Vec::new();
-        for y in 0..10 {
-            for x in 0..10 {
-                self.energy_levels[y][x] += 1;
-                if self.energy_levels[y][x] == 10 {
-                    flash_queue.push((x, y));
-                }
+        for pos in self.energy_levels.positions() {
+            self.energy_levels[pos] += 1;
+            if self.energy_levels[pos] == 10 {
+                flash_queue.push(pos);
             }
         }
 
         let mut count_flashed = 0;
-        let mut flashed = [[false; 10]; 10];
-        while let Some((x, y)) = flash_queue.pop() {
-            flashed[y][x] = true;
+        let mut flashed = Grid::filled(10, 10, false);
+        while let Some(pos) = flash_queue.pop() {
+            flashed[pos] = true;
             count_flashed += 1;
 
             // Increment the neighbors...
-            // North
-            if y > 0 && !flashed[y - 1][x] {
-                self.energy_levels[y - 1][x] += 1;
-                if self.energy_levels[y - 1][x] == 10 {
-                    flash_queue.push((x, y - 1));
-                }
-            }
-            // South
-            if y < 9 && !flashed[y + 1][x] {
-                self.energy_levels[y + 1][x] += 1;
-                if self.energy_levels[y + 1][x] == 10 {
-                    flash_queue.push((x, y + 1));
-                }
-            }
-            // West
-            if x > 0 && !flashed[y][x - 1] {
-                self.energy_levels[y][x - 1] += 1;
-                if self.energy_levels[y][x - 1] == 10 {
-                    flash_queue.push((x - 1, y));
-                }
-            }
-            // East
-            if x < 9 && !flashed[y][x + 1] {
-                self.energy_levels[y][x + 1] += 1;
-                if self.energy_levels[y][x + 1] == 10 {
-                    flash_queue.push((x + 1, y));
-                }
-            }
-            // North-West
-            if x > 0 && y > 0 && !flashed[y - 1][x - 1] {
-                self.energy_levels[y - 1][x - 1] += 1;
-                if self.energy_levels[y - 1][x - 1] == 10 {
-                    flash_queue.push((x - 1, y - 1));
-                }
-            }
-            // North-East
-            if x < 9 && y > 0 && !flashed[y - 1][x + 1] {
-                self.energy_levels[y - 1][x + 1] += 1;
-                if self.energy_levels[y - 1][x + 1] == 10 {
-                    flash_queue.push((x + 1, y - 1));
-                }
-            }
-            // South-West
-            if x > 0 && y < 9 && !flashed[y + 1][x - 1] {
-                self.energy_levels[y + 1][x - 1] += 1;
-                if self.energy_levels[y + 1][x - 1] == 10 {
-                    flash_queue.push((x - 1, y + 1));
+            let neighbors =
+                self.energy_levels.neighbors8(pos).collect::<Vec<_>>();
+            for neighbor in neighbors {
+                if flashed[neighbor] {
+                    continue;
                 }
-            }
-            // South-East
-            if x < 9 && y < 9 && !flashed[y + 1][x + 1] {
-                self.energy_levels[y + 1][x + 1] += 1;
-                if self.energy_levels[y + 1][x + 1] == 10 {
-                    flash_queue.push((x + 1, y + 1));
+                self.energy_levels[neighbor] += 1;
+                if self.energy_levels[neighbor] == 10 {
+                    flash_queue.push(neighbor);
                 }
             }
         }
 
         // Finally, set those flashed to zero
-        for y in 0..10 {
-            for x in 0..10 {
-                if flashed[y][x] {
-                    self.energy_levels[y][x] = 0;
-                }
+        for (pos, &has_flashed) in flashed.iter() {
+            if has_flashed {
+                self.energy_levels[pos] = 0;
             }
         }
 
@@ -133,14 +65,7 @@ impl Octopuses {
 
 impl fmt::Display for Octopuses {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for y in 0..10 {
-            for x in 0..10 {
-                write!(f, "{}", self.energy_levels[y][x])?
-            }
-            writeln!(f, "")?
-        }
-
-        write!(f, "")
+        writeln!(f, "{}", self.energy_levels)
     }
 }
 
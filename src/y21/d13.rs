@@ -1,10 +1,13 @@
+use crate::util::answer::Answer;
+use crate::util::ocr;
+use crate::util::solution::Solution;
 use crate::util::vectors;
 use anyhow::{bail, Context, Result};
 use std::cmp::max;
 use std::collections::HashSet;
 
 #[derive(Clone, Debug)]
-struct Paper {
+pub struct Paper {
     points: Vec<(u32, u32)>,
     width: u32,
     height: u32,
@@ -82,12 +85,22 @@ impl Paper {
         Ok(Paper { points, width, height })
     }
 
+    /// Returns a height x width grid, where a pixel is lit if there is a point on it
+    fn pixels(&self) -> Vec<Vec<bool>> {
+        let mut result =
+            vec![vec![false; self.width as usize]; self.height as usize];
+        for (x, y) in &self.points {
+            result[*y as usize][*x as usize] = true;
+        }
+        result
+    }
+
     fn point_to_hash(input: &(u32, u32)) -> u32 {
         input.0 * 10000 + input.1
     }
 }
 
-type Instructions = Vec<FoldInstr>;
+pub type Instructions = Vec<FoldInstr>;
 
 fn new_instructions(lines: Vec<String>) -> Result<Instructions> {
     let instructions: Result<Vec<FoldInstr>> = lines
@@ -102,7 +115,7 @@ fn new_instructions(lines: Vec<String>) -> Result<Instructions> {
 }
 
 #[derive(Debug)]
-enum FoldInstr {
+pub enum FoldInstr {
     X(u32),
     Y(u32),
 }
@@ -133,46 +146,47 @@ impl FoldInstr {
     }
 }
 
-pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let mut groups = vectors::group(lines);
-    if groups.len() != 2 {
-        bail!("expected input to be split into 2, got {}", groups.len());
-    }
-    let instructions = groups.pop().unwrap();
-    let instructions = new_instructions(instructions)
-        .context("could not create Instructions")?;
-    if instructions.len() < 1 {
-        bail!("zero instructions")
-    }
-    let paper =
-        Paper::new(groups.pop().unwrap()).context("could not create Paper")?;
-
-    // Part 1: Parse the first instruction, and only the first instruction
-    let first_instruction = &instructions[0];
-    let folded_paper =
-        paper.fold(first_instruction).context("could not fold paper")?;
-    let ans1 = Ok(folded_paper.count_points().to_string());
-
-    // Part 2: Fold according to all instructions
-    let mut paper = paper;
-    for instr in &instructions {
-        paper = paper
-            .fold(instr)
-            .context(format!("could not fold using instruction {:?}", instr))?;
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Paper, Instructions);
+
+    fn parse(lines: Vec<String>) -> Result<(Paper, Instructions)> {
+        let mut groups = vectors::group(lines);
+        if groups.len() != 2 {
+            bail!("expected input to be split into 2, got {}", groups.len());
+        }
+        let instructions = groups.pop().unwrap();
+        let instructions = new_instructions(instructions)
+            .context("could not create Instructions")?;
+        if instructions.is_empty() {
+            bail!("zero instructions")
+        }
+        let paper = Paper::new(groups.pop().unwrap())
+            .context("could not create Paper")?;
+
+        Ok((paper, instructions))
     }
-    // Let's find a way to display this to user output
-    let mut ans2: Vec<Vec<bool>> =
-        vec![vec![false; paper.width as usize]; paper.height as usize];
-    for point in &paper.points {
-        let (x, y) = *point;
-        ans2[y as usize][x as usize] = true;
+
+    /// Part 1: Parse the first instruction, and only the first instruction
+    fn part1((paper, instructions): &(Paper, Instructions)) -> Result<Answer> {
+        let first_instruction = &instructions[0];
+        let folded_paper =
+            paper.fold(first_instruction).context("could not fold paper")?;
+        Ok(folded_paper.count_points().into())
     }
-    let ans2: Vec<String> = ans2
-        .iter()
-        .map(|row| row.iter().map(|b| if *b { '█' } else { ' ' }).collect())
-        .collect();
-    let ans2 = ans2.join("\n");
-    let ans2 = Ok(String::from("the following:\n") + &ans2);
 
-    Ok((ans1, ans2))
+    /// Part 2: Fold according to all instructions, and read the paper
+    fn part2((paper, instructions): &(Paper, Instructions)) -> Result<Answer> {
+        let mut paper = paper.clone();
+        for instr in instructions {
+            paper = paper.fold(instr).context(format!(
+                "could not fold using instruction {:?}",
+                instr
+            ))?;
+        }
+        let pixels = paper.pixels();
+        let text = ocr::recognize(&pixels);
+        Ok(Answer::Grid { pixels, text })
+    }
 }
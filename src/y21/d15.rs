@@ -1,108 +1,59 @@
-use anyhow::{bail, Context, Result};
-use priority_queue::DoublePriorityQueue;
-use std::collections::HashSet;
+use anyhow::{Context, Result};
+
+use crate::util::grid::Grid;
+use crate::util::search;
 
 struct Cavern {
-    risks: Vec<Vec<u32>>,
-    width: usize,
-    height: usize,
+    risks: Grid<u32>,
 }
 
 impl Cavern {
     fn new(lines: Vec<String>) -> Result<Cavern> {
-        let height = lines.len();
-        let width = lines.first().unwrap_or(&String::new()).len();
-        if height == 0 || width == 0 {
-            bail!("input cannot be empty");
-        }
-
-        let mut risks = Vec::new();
-        for line in lines {
-            let row: Option<Vec<u32>> =
-                line.chars().map(|x| x.to_digit(10)).collect();
-            let row = row.context(format!(
-                "could not format `{}` as a row of numbers",
-                line
-            ))?;
-            if row.len() != width {
-                bail!(
-                    "expected row `{}` to be of length {}, got {} instead",
-                    line,
-                    width,
-                    row.len()
-                );
-            }
-            risks.push(row);
-        }
-
-        Ok(Cavern { risks, width, height })
+        let risks = Grid::parse_with(&lines, |x| x.to_digit(10))?;
+        Ok(Cavern { risks })
     }
 
-    fn least_risk(&self) -> u32 {
-        let mut total_risks = vec![vec![None; self.width]; self.height];
-        total_risks[0][0] = Some(0);
-        let mut pq = DoublePriorityQueue::new();
-        pq.push((0, 0), 0);
-        let mut done = HashSet::new();
-        while let Some(((x, y), risk)) = pq.pop_min() {
-            if x == self.width - 1 && y == self.height - 1 {
-                return risk;
-            }
-
-            // Append neighbors
-            if x > 1 && !done.contains(&(x - 1, y)) {
-                pq.push_decrease((x - 1, y), risk + self.risks[y][x - 1]);
-            }
-            if x < self.width - 1 && !done.contains(&(x + 1, y)) {
-                pq.push_decrease((x + 1, y), risk + self.risks[y][x + 1]);
-            }
-            if y > 1 && !done.contains(&(x, y - 1)) {
-                pq.push_decrease((x, y - 1), risk + self.risks[y - 1][x]);
-            }
-            if y < self.height - 1 && !done.contains(&(x, y + 1)) {
-                pq.push_decrease((x, y + 1), risk + self.risks[y + 1][x]);
-            }
-
-            // Finally, add popint to the done pile
-            done.insert((x, y));
-        }
-
-        // If we're left with nothing...
-        0
+    /// Least total risk of going from the top left to the bottom right,
+    /// or None if the bottom right cannot be reached
+    fn least_risk(&self) -> Option<u32> {
+        let goal = (self.risks.width() - 1, self.risks.height() - 1);
+        let (risk, _path) = search::dijkstra(
+            (0, 0),
+            |&pos| {
+                self.risks.neighbors4(pos).map(|next| (next, self.risks[next]))
+            },
+            |&pos| pos == goal,
+        )?;
+        Some(risk)
     }
 
     /// Part 2: Expand the cavern
     fn expand(&self) -> Cavern {
-        let orig_width = self.width;
-        let orig_height = self.height;
-
-        let width = orig_width * 5;
-        let height = orig_height * 5;
-        let mut risks = vec![vec![0; width]; height];
+        let orig_width = self.risks.width();
+        let orig_height = self.risks.height();
 
-        for i in 0..5 {
-            for j in 0..5 {
-                let m = (i + j) as u32;
-                let (x_off, y_off) = (orig_width * i, orig_height * j);
-                for x in 0..orig_width {
-                    for y in 0..orig_height {
-                        let o = self.risks[y][x];
-                        risks[y_off + y][x_off + x] = (o + m - 1) % 9 + 1;
-                    }
-                }
-            }
-        }
+        let risks = Grid::from_fn(orig_width * 5, orig_height * 5, |(x, y)| {
+            let m = (x / orig_width + y / orig_height) as u32;
+            let o = self.risks[(x % orig_width, y % orig_height)];
+            (o + m - 1) % 9 + 1
+        });
 
-        Cavern { risks, width, height }
+        Cavern { risks }
     }
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
     let cavern = Cavern::new(lines).context("could not parse input")?;
 
-    let ans1 = Ok(cavern.least_risk().to_string());
+    let ans1 = cavern
+        .least_risk()
+        .map(|risk| risk.to_string())
+        .context("could not reach the bottom right");
     let cavern = cavern.expand();
-    let ans2 = Ok(cavern.least_risk().to_string());
+    let ans2 = cavern
+        .least_risk()
+        .map(|risk| risk.to_string())
+        .context("could not reach the bottom right");
 
     Ok((ans1, ans2))
 }
@@ -0,0 +1,207 @@
+use std::ops::Add;
+
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+/// A snailfish Number, stored as its regular numbers from left to right
+/// along with how many pairs each is nested in.
+/// For example, `[[1,2],3]` is `[(1, 2), (2, 2), (3, 1)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    values: Vec<(u32, usize)>,
+}
+
+impl Number {
+    fn new(input: &str) -> Result<Number> {
+        let mut values = vec![];
+        let mut depth = 0;
+        let mut digits = String::new();
+        for c in input.chars() {
+            if c.is_ascii_digit() {
+                digits.push(c);
+                continue;
+            }
+            if !digits.is_empty() {
+                values.push((digits.parse()?, depth));
+                digits.clear();
+            }
+            match c {
+                '[' => depth += 1,
+                ']' => {
+                    if depth == 0 {
+                        bail!("unmatched `]`");
+                    }
+                    depth -= 1;
+                }
+                ',' => {}
+                _ => bail!("invalid character `{}`", c),
+            }
+        }
+        if depth != 0 || !digits.is_empty() || values.is_empty() {
+            bail!("incomplete number");
+        }
+        Ok(Number { values })
+    }
+
+    /// Explodes the leftmost pair nested inside four pairs.
+    /// Returns false if there is none.
+    fn explode(&mut self) -> bool {
+        let index = match self.values.iter().position(|(_, depth)| *depth > 4) {
+            Some(index) => index,
+            None => return false,
+        };
+        let (left, depth) = self.values[index];
+        let (right, _) = self.values[index + 1];
+        if index > 0 {
+            self.values[index - 1].0 += left;
+        }
+        if index + 2 < self.values.len() {
+            self.values[index + 2].0 += right;
+        }
+        self.values.splice(index..index + 2, [(0, depth - 1)]);
+        true
+    }
+
+    /// Splits the leftmost regular number that is 10 or greater.
+    /// Returns false if there is none.
+    fn split(&mut self) -> bool {
+        let index = match self.values.iter().position(|(value, _)| *value >= 10)
+        {
+            Some(index) => index,
+            None => return false,
+        };
+        let (value, depth) = self.values[index];
+        self.values.splice(
+            index..index + 1,
+            [(value / 2, depth + 1), (value.div_ceil(2), depth + 1)],
+        );
+        true
+    }
+
+    fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    fn magnitude(&self) -> u32 {
+        let mut values = self.values.clone();
+        while values.len() > 1 {
+            // The leftmost two numbers at the deepest level always form a pair
+            let deepest = values.iter().map(|(_, depth)| *depth).max().unwrap();
+            let index = values
+                .windows(2)
+                .position(|w| w[0].1 == deepest && w[1].1 == deepest)
+                .unwrap();
+            let magnitude = 3 * values[index].0 + 2 * values[index + 1].0;
+            values.splice(index..index + 2, [(magnitude, deepest - 1)]);
+        }
+        values[0].0
+    }
+}
+
+impl Add for &Number {
+    type Output = Number;
+
+    fn add(self, rhs: &Number) -> Number {
+        let values = self
+            .values
+            .iter()
+            .chain(rhs.values.iter())
+            .map(|(value, depth)| (*value, depth + 1))
+            .collect();
+        let mut result = Number { values };
+        result.reduce();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(input: &str) -> Number {
+        Number::new(input).unwrap()
+    }
+
+    #[test]
+    fn test_reduce() {
+        let cases = [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ];
+        for (input, expected) in cases {
+            let mut n = number(input);
+            assert!(n.explode());
+            assert_eq!(n, number(expected));
+        }
+
+        assert_eq!(
+            &number("[[[[4,3],4],4],[7,[[8,4],9]]]") + &number("[1,1]"),
+            number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")
+        );
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let cases = [
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            ("[[[[3,0],[5,3]],[4,4]],[5,5]]", 791),
+            ("[[[[5,0],[7,4]],[5,5]],[6,6]]", 1137),
+            ("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]", 3488),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(number(input).magnitude(), expected);
+        }
+        assert!(Number::new("[1,2").is_err());
+        assert!(Number::new("[1,a]").is_err());
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Number>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Number>> {
+        let numbers = lines
+            .iter()
+            .map(|line| {
+                Number::new(line)
+                    .with_context(|| format!("could not parse `{}`", line))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if numbers.is_empty() {
+            bail!("there are no numbers");
+        }
+        Ok(numbers)
+    }
+
+    /// Part 1: Add all numbers in order
+    fn part1(numbers: &Vec<Number>) -> Result<Answer> {
+        let sum = numbers[1..]
+            .iter()
+            .fold(numbers[0].clone(), |acc, number| &acc + number);
+        Ok(sum.magnitude().into())
+    }
+
+    /// Part 2: Add any two different numbers
+    fn part2(numbers: &Vec<Number>) -> Result<Answer> {
+        let mut largest = None;
+        for (i, a) in numbers.iter().enumerate() {
+            for (j, b) in numbers.iter().enumerate() {
+                if i != j {
+                    largest = largest.max(Some((a + b).magnitude()));
+                }
+            }
+        }
+        Ok(largest.context("there must be at least two numbers")?.into())
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::{bail, Context, Result};
+use itertools::{iproduct, Itertools};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::geom::Point3;
+use crate::util::solution::Solution;
+use crate::util::vectors::group;
+
+/// How many beacons two scanners must share to be sure they overlap
+const OVERLAP: usize = 12;
+
+/// A Rotation reorders the axes of a Point3 and flips some of them,
+/// without turning it into its mirror image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rotation {
+    /// The new x, y, z are the old coordinates at these indices...
+    axes: [usize; 3],
+    /// ...multiplied by these signs.
+    signs: [i64; 3],
+}
+
+impl Rotation {
+    /// All 24 ways a scanner could be facing
+    fn all() -> Vec<Rotation> {
+        let mut result = vec![];
+        for axes in (0..3).permutations(3) {
+            let axes = [axes[0], axes[1], axes[2]];
+            let inversions = (0..3)
+                .tuple_combinations()
+                .filter(|&(i, j)| axes[i] > axes[j])
+                .count();
+            for (sx, sy, sz) in iproduct!([-1, 1], [-1, 1], [-1, 1]) {
+                let signs = [sx, sy, sz];
+                // Odd permutations mirror, unless an odd number of axes is flipped
+                let flips = signs.iter().filter(|s| **s < 0).count();
+                if (inversions + flips) % 2 == 0 {
+                    result.push(Rotation { axes, signs });
+                }
+            }
+        }
+        result
+    }
+
+    fn apply(&self, point: Point3) -> Point3 {
+        let coords = [point.x, point.y, point.z];
+        Point3::new(
+            coords[self.axes[0]] * self.signs[0],
+            coords[self.axes[1]] * self.signs[1],
+            coords[self.axes[2]] * self.signs[2],
+        )
+    }
+}
+
+/// Beacons detected by a scanner, relative to the scanner
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    beacons: Vec<Point3>,
+    /// How many times each squared distance between two beacons appears,
+    /// which stays the same however the scanner is rotated
+    fingerprint: HashMap<i64, usize>,
+}
+
+impl Scanner {
+    fn new(lines: &[String]) -> Result<Scanner> {
+        let (header, beacons) =
+            lines.split_first().context("scanner has no lines")?;
+        if sscanf!(header, "--- scanner {} ---", usize).is_err() {
+            bail!("invalid header `{}`", header);
+        }
+        let beacons = beacons
+            .iter()
+            .map(|line| {
+                sscanf!(line, "{},{},{}", i64, i64, i64)
+                    .ok()
+                    .map(|(x, y, z)| Point3::new(x, y, z))
+                    .with_context(|| format!("invalid beacon `{}`", line))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let fingerprint = beacons
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| squared_distance(*a, *b))
+            .counts();
+        Ok(Scanner { beacons, fingerprint })
+    }
+
+    /// Finds how to rotate and move self so that at least OVERLAP of its beacons
+    /// match beacons of other, where other's beacons are already where they should be.
+    /// Returns where self's beacons end up and where self is.
+    fn align(
+        &self,
+        other: &Scanner,
+        other_beacons: &[Point3],
+        rotations: &[Rotation],
+    ) -> Option<(Vec<Point3>, Point3)> {
+        // Sharing OVERLAP beacons means sharing the distances between them
+        let shared = self
+            .fingerprint
+            .iter()
+            .map(|(d, count)| count.min(other.fingerprint.get(d).unwrap_or(&0)))
+            .sum::<usize>();
+        if shared < OVERLAP * (OVERLAP - 1) / 2 {
+            return None;
+        }
+        for rotation in rotations {
+            let rotated =
+                self.beacons.iter().map(|b| rotation.apply(*b)).collect_vec();
+            let mut offsets = HashMap::new();
+            for a in other_beacons {
+                for b in &rotated {
+                    let offset = *a - *b;
+                    let count = offsets.entry(offset).or_insert(0);
+                    *count += 1;
+                    if *count >= OVERLAP {
+                        let moved = rotated.iter().map(|b| *b + offset);
+                        return Some((moved.collect(), offset));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+fn squared_distance(a: Point3, b: Point3) -> i64 {
+    let d = a - b;
+    d.x * d.x + d.y * d.y + d.z * d.z
+}
+
+/// Where all beacons and scanners are, relative to the first scanner
+pub struct Map {
+    beacons: HashSet<Point3>,
+    scanners: Vec<Point3>,
+}
+
+impl Map {
+    fn new(scanners: &[Scanner]) -> Result<Map> {
+        let rotations = Rotation::all();
+        // Beacons and position of each scanner that has been aligned
+        let mut aligned: Vec<Option<(Vec<Point3>, Point3)>> =
+            vec![None; scanners.len()];
+        aligned[0] = Some((scanners[0].beacons.clone(), Point3::ORIGIN));
+        let mut queue = VecDeque::from([0]);
+        while let Some(known) = queue.pop_front() {
+            let known_beacons = aligned[known].as_ref().unwrap().0.clone();
+            for (index, scanner) in scanners.iter().enumerate() {
+                if aligned[index].is_some() {
+                    continue;
+                }
+                let alignment =
+                    scanner.align(&scanners[known], &known_beacons, &rotations);
+                if alignment.is_some() {
+                    aligned[index] = alignment;
+                    queue.push_back(index);
+                }
+            }
+        }
+
+        let aligned = aligned
+            .into_iter()
+            .enumerate()
+            .map(|(index, alignment)| {
+                alignment.with_context(|| {
+                    format!(
+                        "scanner {} does not overlap with the others",
+                        index
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let beacons = aligned
+            .iter()
+            .flat_map(|(beacons, _)| beacons.iter().copied())
+            .collect();
+        let scanners = aligned.into_iter().map(|(_, pos)| pos).collect();
+        Ok(Map { beacons, scanners })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        let point = Point3::new(1, 2, 3);
+        let rotated = rotations.iter().map(|r| r.apply(point)).collect_vec();
+        assert_eq!(rotated.iter().unique().count(), 24);
+        assert!(rotated.contains(&point));
+        // A rotation keeps (1, 2, 3) right-handed, unlike its mirror (-1, 2, 3)
+        assert!(rotated.contains(&Point3::new(-1, -2, 3)));
+        assert!(!rotated.contains(&Point3::new(-1, 2, 3)));
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Map;
+
+    fn parse(lines: Vec<String>) -> Result<Map> {
+        let scanners = group(lines)
+            .iter()
+            .enumerate()
+            .map(|(index, lines)| {
+                Scanner::new(lines).with_context(|| {
+                    format!("could not parse scanner {}", index)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if scanners.is_empty() {
+            bail!("there are no scanners");
+        }
+        Map::new(&scanners)
+    }
+
+    /// Part 1: Count all beacons
+    fn part1(map: &Map) -> Result<Answer> {
+        Ok(map.beacons.len().into())
+    }
+
+    /// Part 2: Find the two scanners furthest apart
+    fn part2(map: &Map) -> Result<Answer> {
+        let distance = map
+            .scanners
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a.manhattan(*b))
+            .max()
+            .unwrap_or(0);
+        Ok(distance.into())
+    }
+}
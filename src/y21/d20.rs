@@ -1,13 +1,12 @@
+use crate::util::grid::Grid;
 use crate::util::vectors;
 use anyhow::{bail, Context, Result};
 use std::fmt;
 
 struct Image {
     enhancement_algorithm: [bool; 512],
-    image: Vec<Vec<bool>>,
+    image: Grid<bool>,
     space: bool,
-    width: usize,
-    height: usize,
 }
 
 impl Image {
@@ -43,32 +42,14 @@ impl Image {
             }
         }
 
-        let image_rows = &groups[1];
-        if image_rows.len() == 0 || image_rows[0].len() == 0 {
-            bail!("empty image rows");
-        }
-        let (width, height) = (image_rows[0].len(), image_rows.len());
-        let mut image = Vec::new();
-        for row in image_rows {
-            if row.len() != width {
-                bail!(
-                    "expected row to be of length {}, got {} instead",
-                    width,
-                    row.len()
-                );
-            }
-            let row: Result<Vec<bool>> = row
-                .chars()
-                .map(|c| match c {
-                    '#' => Ok(true),
-                    '.' => Ok(false),
-                    c => bail!("unknown character {}", c),
-                })
-                .collect();
-            image.push(row.context("could not parse some character")?);
-        }
+        let image = Grid::parse_with(&groups[1], |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .context("could not parse image")?;
 
-        Ok(Image { enhancement_algorithm, image, space: false, width, height })
+        Ok(Image { enhancement_algorithm, image, space: false })
     }
 
     /// Determine what the value at some pixel (x,y) will be for the *enhanced* image.
@@ -80,12 +61,7 @@ impl Image {
             if x < dx || y < dy {
                 return self.space;
             }
-            let (x, y) = (x - dx, y - dy);
-            if x >= self.width || y >= self.height {
-                return self.space;
-            }
-
-            self.image[y][x]
+            *self.image.get((x - dx, y - dy)).unwrap_or(&self.space)
         };
 
         self.get_next([
@@ -103,22 +79,16 @@ impl Image {
 
     /// Iterate the current one
     fn enhance(&mut self) {
-        let width = self.width + 2;
-        let height = self.height + 2;
-        let mut image = vec![vec![false; width]; height];
-        for y in 0..height {
-            for x in 0..width {
-                image[y][x] = self.at_enhanced(x, y);
-            }
-        }
+        let width = self.image.width() + 2;
+        let height = self.image.height() + 2;
+        let image =
+            Grid::from_fn(width, height, |(x, y)| self.at_enhanced(x, y));
 
         if self.enhancement_algorithm[0] && !self.enhancement_algorithm[511] {
             // The input "flashes" bet. all lit and all dark.
             // Thanks, Eric Wastl.
             self.space = !self.space;
         };
-        self.width = width;
-        self.height = height;
         self.image = image;
     }
 
@@ -135,19 +105,14 @@ impl Image {
     }
 
     fn count_lit(&self) -> usize {
-        self.image.iter().map(|row| row.iter().filter(|b| **b).count()).sum()
+        self.image.iter().filter(|(_, b)| **b).count()
     }
 }
 
 impl fmt::Display for Image {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                write!(f, "{}", if self.image[y][x] { '#' } else { '.' })?
-            }
-            writeln!(f, "")?
-        }
-        write!(f, "")
+        let image = self.image.map(|b| if *b { '#' } else { '.' });
+        writeln!(f, "{}", image)
     }
 }
 
@@ -1,5 +1,7 @@
 use anyhow::{bail, Context, Result};
 
+use crate::trace;
+
 fn add_bound_10(a: u32, b: u32) -> u32 {
     (a + b - 1) % 10 + 1
 }
@@ -94,7 +96,7 @@ impl DiracMultiverse {
             self.iter();
             count += 1;
         }
-        dbg!(count);
+        trace!(Debug, "finished after {} iterations", count);
     }
 
     /// Iterates through the entire grid
@@ -1,11 +1,40 @@
 use anyhow::{bail, Context, Result};
 use sscanf::sscanf;
+use std::collections::HashMap;
+
+use crate::util::interval::Interval;
+
+/// A Cuboid is the set of cubes whose coordinates are within all of x, y, and z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Cuboid {
+    x: Interval<i64>,
+    y: Interval<i64>,
+    z: Interval<i64>,
+}
+
+impl Cuboid {
+    /// Cubes in both self and other, or None if they don't overlap.
+    fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let result = Cuboid {
+            x: self.x.intersection(other.x),
+            y: self.y.intersection(other.y),
+            z: self.z.intersection(other.z),
+        };
+        if result.x.is_empty() || result.y.is_empty() || result.z.is_empty() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    fn volume(&self) -> i64 {
+        self.x.len() * self.y.len() * self.z.len()
+    }
+}
 
 #[derive(Debug)]
 struct Step {
-    x: (i32, i32),
-    y: (i32, i32),
-    z: (i32, i32),
+    cuboid: Cuboid,
     turn_on: bool, // If false, turn off
 }
 
@@ -15,12 +44,12 @@ impl Step {
             input,
             "{} x={}..{},y={}..{},z={}..{}",
             String,
-            i32,
-            i32,
-            i32,
-            i32,
-            i32,
-            i32
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64
         );
         if let Err(_) = parsed_input {
             bail!("cannot parse input");
@@ -37,84 +66,96 @@ impl Step {
             vv => bail!("invalid instruction `{}`", vv),
         }
 
-        Ok(Step { x: (x1, x2), y: (y1, y2), z: (z1, z2), turn_on })
+        let cuboid = Cuboid {
+            x: Interval::from_inclusive(x1, x2),
+            y: Interval::from_inclusive(y1, y2),
+            z: Interval::from_inclusive(z1, z2),
+        };
+        Ok(Step { cuboid, turn_on })
     }
 }
 
-struct NaiveCube {
-    /// If radius is 50, the Cube is defined
-    /// such that x,y,z can be from -50 to 50 inclusive.
-    radius: usize,
-
-    points: Vec<Vec<Vec<bool>>>,
+/// Reactor keeps track of the lit cubes as signed Cuboids:
+/// the number of lit cubes is the sum of the volumes of the Cuboids,
+/// each counted positively or negatively `count` times.
+/// This way, overlapping steps never need to be split into smaller Cuboids.
+struct Reactor {
+    /// Cubes outside of region are ignored.
+    region: Option<Cuboid>,
+    counts: HashMap<Cuboid, i64>,
 }
 
-impl NaiveCube {
-    fn new(radius: usize) -> NaiveCube {
-        let side_len = radius * 2 + 1;
-        let points = vec![vec![vec![false; side_len]; side_len]; side_len];
-        NaiveCube { radius, points }
+impl Reactor {
+    fn new(region: Option<Cuboid>) -> Reactor {
+        Reactor { region, counts: HashMap::new() }
     }
 
     fn run(&mut self, step: &Step) {
-        for x in step.x.0..step.x.1 + 1 {
-            for y in step.y.0..step.y.1 + 1 {
-                for z in step.z.0..step.z.1 + 1 {
-                    self.set(x, y, z, step.turn_on);
-                }
+        let cuboid = match &self.region {
+            None => step.cuboid,
+            Some(region) => match region.intersection(&step.cuboid) {
+                None => return,
+                Some(cuboid) => cuboid,
+            },
+        };
+
+        // Turn off everything in cuboid by cancelling out its overlap with each Cuboid.
+        // Turning on is then the same as adding cuboid once.
+        let mut updates = HashMap::new();
+        for (existing, count) in &self.counts {
+            if let Some(overlap) = existing.intersection(&cuboid) {
+                *updates.entry(overlap).or_insert(0) -= count;
             }
         }
-    }
-
-    fn set(&mut self, x: i32, y: i32, z: i32, b: bool) {
-        if !self.is_in_bounds_pt(x, y, z) {
-            return;
+        if step.turn_on {
+            *updates.entry(cuboid).or_insert(0) += 1;
         }
-
-        // Now, do the needful
-        let r = self.radius as i32;
-        let (x, y, z) = (x + r, y + r, z + r);
-        self.points[z as usize][y as usize][x as usize] = b;
+        for (cuboid, count) in updates {
+            *self.counts.entry(cuboid).or_insert(0) += count;
+        }
+        self.counts.retain(|_, count| *count != 0);
     }
 
-    fn is_in_bounds_pt(&self, x: i32, y: i32, z: i32) -> bool {
-        let radius = self.radius as i32;
-        x >= -radius
-            && x <= radius
-            && y >= -radius
-            && y <= radius
-            && z >= -radius
-            && z <= radius
+    fn count_on(&self) -> i64 {
+        self.counts.iter().map(|(cuboid, count)| cuboid.volume() * count).sum()
     }
+}
 
-    fn is_in_bounds_step(&self, step: &Step) -> bool {
-        let radius = self.radius as i32;
-        let ((x1, x2), (y1, y2), (z1, z2)) = (step.x, step.y, step.z);
-        x1 >= -radius
-            && x2 <= radius
-            && y1 >= -radius
-            && y2 <= radius
-            && z1 >= -radius
-            && z2 <= radius
+/// Runs all steps and counts how many cubes within region are on.
+fn reboot(steps: &[Step], region: Option<Cuboid>) -> i64 {
+    let mut reactor = Reactor::new(region);
+    for step in steps {
+        reactor.run(step);
     }
+    reactor.count_on()
+}
 
-    fn count_on(&self) -> usize {
-        let mut result = 0;
-        for sheet in &self.points {
-            for row in sheet {
-                for pt in row {
-                    if *pt {
-                        result += 1;
-                    }
-                }
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reboot() {
+        let steps = [
+            "on x=10..12,y=10..12,z=10..12",
+            "on x=11..13,y=11..13,z=11..13",
+            "off x=9..11,y=9..11,z=9..11",
+            "on x=10..10,y=10..10,z=10..10",
+        ]
+        .map(|line| Step::new(line).unwrap());
+        let expected = [27, 27 + 19, 27 + 19 - 8, 27 + 19 - 8 + 1];
+        for (count, expected) in (1..=steps.len()).zip(expected) {
+            assert_eq!(reboot(&steps[..count], None), expected);
         }
-        result
-    }
-}
 
-fn solve_part_2() -> Result<String> {
-    bail!("unimplemented")
+        let region = Cuboid {
+            x: Interval::from_inclusive(11, 11),
+            y: Interval::from_inclusive(9, 20),
+            z: Interval::from_inclusive(9, 20),
+        };
+        // At x=11, 14 cubes are turned on, and then the 4 cubes at y,z=10..11 are turned off
+        assert_eq!(reboot(&steps, Some(region)), 10);
+    }
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
@@ -124,19 +165,14 @@ pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
         .collect();
     let steps = steps.context("could not parse input")?;
 
-    // Part 1: Cube is represented by a 3D matrix of booleans.
-    let mut cube = NaiveCube::new(50);
-    for step in &steps {
-        if !cube.is_in_bounds_step(step) {
-            break;
-        }
-        cube.run(step);
-    }
-    let ans1 = Ok(cube.count_on().to_string());
+    // Part 1: Only consider the cubes from -50 to 50
+    let initialization = Interval::from_inclusive(-50, 50);
+    let region =
+        Cuboid { x: initialization, y: initialization, z: initialization };
+    let ans1 = Ok(reboot(&steps, Some(region)).to_string());
 
-    // Part 2. In my 16GB RAM environment this crashes.
-    // TODO: Implement using intervals and whatever
-    let ans2 = solve_part_2();
+    // Part 2: Consider all cubes
+    let ans2 = Ok(reboot(&steps, None).to_string());
 
     Ok((ans1, ans2))
 }
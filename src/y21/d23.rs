@@ -0,0 +1,249 @@
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::search;
+use crate::util::solution::Solution;
+
+const HALLWAY_LENGTH: usize = 11;
+const EMPTY: u8 = b'.';
+
+/// Rows that Part 2 unfolds between the two rows of the rooms
+const UNFOLDED: [&str; 2] = ["DCBA", "DBAC"];
+
+/// Energy it takes for an amphipod to take one step
+fn energy(amphipod: u8) -> usize {
+    match amphipod {
+        b'A' => 1,
+        b'B' => 10,
+        b'C' => 100,
+        _ => 1000,
+    }
+}
+
+/// Position in the hallway just outside of a room
+fn door(room: usize) -> usize {
+    2 + 2 * room
+}
+
+/// Which amphipod a room is meant for
+fn owner(room: usize) -> u8 {
+    b'A' + room as u8
+}
+
+/// A Burrow is where every amphipod is at some moment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Burrow {
+    hallway: [u8; HALLWAY_LENGTH],
+    /// Amphipods in each room, from the one nearest the hallway
+    rooms: [Vec<u8>; 4],
+}
+
+impl Burrow {
+    fn new(lines: &[String]) -> Result<Burrow> {
+        if lines.len() < 4 {
+            bail!("expected at least 4 lines, got {}", lines.len());
+        }
+        let mut rooms: [Vec<u8>; 4] = Default::default();
+        for line in &lines[2..lines.len() - 1] {
+            let line = line.as_bytes();
+            for (room, amphipods) in rooms.iter_mut().enumerate() {
+                match line.get(3 + 2 * room) {
+                    Some(c @ b'A'..=b'D') => amphipods.push(*c),
+                    _ => bail!(
+                        "invalid room row `{}`",
+                        String::from_utf8_lossy(line)
+                    ),
+                }
+            }
+        }
+        Ok(Burrow { hallway: [EMPTY; HALLWAY_LENGTH], rooms })
+    }
+
+    /// Inserts rows of amphipods between the first and the rest of the rooms' rows.
+    fn unfold(&self, rows: &[&str]) -> Burrow {
+        let mut result = self.clone();
+        for (room, amphipods) in result.rooms.iter_mut().enumerate() {
+            for (depth, row) in rows.iter().enumerate() {
+                amphipods.insert(1 + depth, row.as_bytes()[room]);
+            }
+        }
+        result
+    }
+
+    fn depth(&self) -> usize {
+        self.rooms[0].len()
+    }
+
+    /// Whether a room only has amphipods that are meant for it
+    fn is_ready(&self, room: usize) -> bool {
+        self.rooms[room].iter().all(|c| *c == EMPTY || *c == owner(room))
+    }
+
+    fn is_organized(&self) -> bool {
+        (0..4).all(|room| self.rooms[room].iter().all(|c| *c == owner(room)))
+    }
+
+    /// Whether nothing is in the hallway strictly between from and to, and at to
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let path = if from < to { from + 1..=to } else { to..=from - 1 };
+        self.hallway[path].iter().all(|c| *c == EMPTY)
+    }
+
+    /// Every Burrow one amphipod can move to, and how much energy it takes.
+    /// An amphipod either leaves its room for the hallway,
+    /// or enters its own room from the hallway.
+    fn moves(&self) -> Vec<(Burrow, usize)> {
+        let mut result = vec![];
+
+        for (pos, &amphipod) in self.hallway.iter().enumerate() {
+            if amphipod == EMPTY {
+                continue;
+            }
+            let room = (amphipod - b'A') as usize;
+            if !self.is_ready(room) || !self.is_clear(pos, door(room)) {
+                continue;
+            }
+            let depth =
+                self.rooms[room].iter().rposition(|c| *c == EMPTY).unwrap();
+            let mut next = self.clone();
+            next.hallway[pos] = EMPTY;
+            next.rooms[room][depth] = amphipod;
+            let steps = pos.abs_diff(door(room)) + depth + 1;
+            result.push((next, steps * energy(amphipod)));
+        }
+
+        for room in 0..4 {
+            if self.is_ready(room) {
+                continue;
+            }
+            let depth = match self.rooms[room].iter().position(|c| *c != EMPTY)
+            {
+                Some(depth) => depth,
+                None => continue,
+            };
+            let amphipod = self.rooms[room][depth];
+            for pos in 0..HALLWAY_LENGTH {
+                // Amphipods never stop right outside of a room
+                if (0..4).any(|r| door(r) == pos)
+                    || !self.is_clear(door(room), pos)
+                {
+                    continue;
+                }
+                let mut next = self.clone();
+                next.rooms[room][depth] = EMPTY;
+                next.hallway[pos] = amphipod;
+                let steps = depth + 1 + pos.abs_diff(door(room));
+                result.push((next, steps * energy(amphipod)));
+            }
+        }
+
+        result
+    }
+
+    /// Least energy to organize the amphipods if nothing were in the way.
+    /// Each amphipod must at least walk to the nearest cell of its room.
+    fn estimate(&self) -> usize {
+        let mut result = 0;
+        for (pos, &amphipod) in self.hallway.iter().enumerate() {
+            if amphipod != EMPTY {
+                let room = (amphipod - b'A') as usize;
+                result += (pos.abs_diff(door(room)) + 1) * energy(amphipod);
+            }
+        }
+        for (room, amphipods) in self.rooms.iter().enumerate() {
+            for (depth, &amphipod) in amphipods.iter().enumerate() {
+                if amphipod == EMPTY {
+                    continue;
+                }
+                let target = (amphipod - b'A') as usize;
+                let steps = if target != room {
+                    depth + 1 + door(room).abs_diff(door(target)) + 1
+                } else if amphipods[depth..].iter().any(|c| *c != amphipod) {
+                    // Has to make way for someone below, and come back
+                    depth + 1 + 2 + 1
+                } else {
+                    0
+                };
+                result += steps * energy(amphipod);
+            }
+        }
+        result
+    }
+
+    /// Least energy to organize the amphipods, if they can be organized at all
+    fn organize(&self) -> Option<usize> {
+        let (energy, _) = search::astar(
+            self.clone(),
+            Burrow::moves,
+            Burrow::estimate,
+            Burrow::is_organized,
+        )?;
+        Some(energy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn burrow(rooms: &[&str]) -> Burrow {
+        let mut lines =
+            vec!["#############".to_string(), "#...........#".to_string()];
+        lines.extend(rooms.iter().map(|row| {
+            let row = row.chars().map(|c| c.to_string()).collect::<Vec<_>>();
+            format!("###{}###", row.join("#"))
+        }));
+        lines.push("  #########".to_string());
+        Burrow::new(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_organize() {
+        assert_eq!(burrow(&["ABCD", "ABCD"]).organize(), Some(0));
+        // A steps out of B's room to the right, B walks over to its room,
+        // and then A walks back to its room
+        assert_eq!(burrow(&["BACD", "ABCD"]).organize(), Some(2 + 4 * 10 + 4));
+        assert_eq!(burrow(&["BACD", "ABCD"]).unfold(&["ABCD"]).depth(), 3);
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+
+    fn parse(lines: Vec<String>) -> Result<Burrow> {
+        let burrow = Burrow::new(&lines)?;
+        for amphipod in b'A'..=b'D' {
+            let count = burrow
+                .rooms
+                .iter()
+                .flatten()
+                .filter(|c| **c == amphipod)
+                .count();
+            if count != burrow.depth() {
+                bail!(
+                    "expected {} of amphipod {}, got {}",
+                    burrow.depth(),
+                    amphipod as char,
+                    count
+                );
+            }
+        }
+        Ok(burrow)
+    }
+
+    fn part1(burrow: &Burrow) -> Result<Answer> {
+        let energy = burrow.organize().context("cannot organize amphipods")?;
+        Ok(energy.into())
+    }
+
+    /// Part 2: The rooms are deeper than they look
+    fn part2(burrow: &Burrow) -> Result<Answer> {
+        let energy = burrow
+            .unfold(&UNFOLDED)
+            .organize()
+            .context("cannot organize amphipods")?;
+        Ok(energy.into())
+    }
+}
@@ -0,0 +1,310 @@
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+/// Number of digits in a model number
+const DIGITS: usize = 14;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    /// One of the variables w, x, y, z
+    Variable(usize),
+    Number(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Inp(usize),
+    Add(usize, Operand),
+    Mul(usize, Operand),
+    Div(usize, Operand),
+    Mod(usize, Operand),
+    Eql(usize, Operand),
+}
+
+fn variable(input: &str) -> Result<usize> {
+    match input {
+        "w" => Ok(0),
+        "x" => Ok(1),
+        "y" => Ok(2),
+        "z" => Ok(3),
+        _ => bail!("invalid variable `{}`", input),
+    }
+}
+
+impl Operand {
+    fn new(input: &str) -> Result<Operand> {
+        match input.parse() {
+            Ok(n) => Ok(Operand::Number(n)),
+            Err(_) => Ok(Operand::Variable(variable(input)?)),
+        }
+    }
+}
+
+impl Instruction {
+    fn new(input: &str) -> Result<Instruction> {
+        let words = input.split(' ').collect::<Vec<_>>();
+        let instruction = match words[..] {
+            ["inp", a] => Instruction::Inp(variable(a)?),
+            [op, a, b] => {
+                let (a, b) = (variable(a)?, Operand::new(b)?);
+                match op {
+                    "add" => Instruction::Add(a, b),
+                    "mul" => Instruction::Mul(a, b),
+                    "div" => Instruction::Div(a, b),
+                    "mod" => Instruction::Mod(a, b),
+                    "eql" => Instruction::Eql(a, b),
+                    _ => bail!("invalid operation `{}`", op),
+                }
+            }
+            _ => bail!("invalid instruction `{}`", input),
+        };
+        Ok(instruction)
+    }
+}
+
+/// Runs the program, reading from inputs for each `inp`.
+/// Returns the variables w, x, y, z at the end,
+/// or an error if inputs run out, or if the program divides by zero.
+fn run(program: &[Instruction], inputs: &[i64]) -> Result<[i64; 4]> {
+    let mut vars = [0; 4];
+    let mut inputs = inputs.iter();
+    for instruction in program {
+        let value = |operand: &Operand| match *operand {
+            Operand::Variable(v) => vars[v],
+            Operand::Number(n) => n,
+        };
+        match instruction {
+            Instruction::Inp(a) => {
+                vars[*a] = *inputs.next().context("ran out of inputs")?;
+            }
+            Instruction::Add(a, b) => vars[*a] += value(b),
+            Instruction::Mul(a, b) => vars[*a] *= value(b),
+            Instruction::Div(a, b) => match value(b) {
+                0 => bail!("division by zero"),
+                b => vars[*a] /= b,
+            },
+            Instruction::Mod(a, b) => match (vars[*a], value(b)) {
+                (a, b) if a < 0 || b <= 0 => bail!("invalid mod {} {}", a, b),
+                (_, b) => vars[*a] %= b,
+            },
+            Instruction::Eql(a, b) => vars[*a] = (vars[*a] == value(b)) as i64,
+        }
+    }
+    Ok(vars)
+}
+
+/// MONAD checks each digit with the same block of instructions, but different numbers.
+/// A block either pushes `digit + offset` onto z, used as a stack of base-26 numbers,
+/// or pops a number from z and compares it with `digit - check`.
+/// z ends at zero only if every pop matches, and pushes nothing back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Push { offset: i64 },
+    Pop { check: i64 },
+}
+
+/// Instructions of each block, where `_` are the numbers that differ between blocks
+const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z _", "add x _", "eql x w",
+    "eql x 0", "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y",
+    "mul y 0", "add y w", "add y _", "mul y x", "add z y",
+];
+
+impl Block {
+    fn new(lines: &[String]) -> Result<Block> {
+        let mut numbers = vec![];
+        for (line, expected) in lines.iter().zip(BLOCK) {
+            match expected.strip_suffix('_') {
+                Some(prefix) => {
+                    let number = line
+                        .strip_prefix(prefix)
+                        .and_then(|n| n.parse::<i64>().ok())
+                        .with_context(|| {
+                            format!("expected `{}`, got `{}`", expected, line)
+                        })?;
+                    numbers.push(number);
+                }
+                None if line == expected => {}
+                None => bail!("expected `{}`, got `{}`", expected, line),
+            }
+        }
+        match numbers[..] {
+            [1, check, offset] if check > 9 => Ok(Block::Push { offset }),
+            [26, check, _] => Ok(Block::Pop { check }),
+            _ => bail!("unexpected numbers {:?}", numbers),
+        }
+    }
+}
+
+pub struct Monad {
+    program: Vec<Instruction>,
+    /// For each pair of a pushing and a popping digit,
+    /// how much bigger the popping digit must be than the pushing digit
+    pairs: Vec<(usize, usize, i64)>,
+}
+
+impl Monad {
+    fn new(lines: &[String]) -> Result<Monad> {
+        if lines.len() != DIGITS * BLOCK.len() {
+            bail!(
+                "expected {} instructions, got {}",
+                DIGITS * BLOCK.len(),
+                lines.len()
+            );
+        }
+        let program = lines
+            .iter()
+            .map(|line| {
+                Instruction::new(line)
+                    .with_context(|| format!("could not parse `{}`", line))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut stack = vec![];
+        let mut pairs = vec![];
+        for (digit, lines) in lines.chunks(BLOCK.len()).enumerate() {
+            let block = Block::new(lines).with_context(|| {
+                format!("block {} is not like MONAD", digit)
+            })?;
+            match block {
+                Block::Push { offset } => stack.push((digit, offset)),
+                Block::Pop { check } => {
+                    let (pushed, offset) = stack.pop().with_context(|| {
+                        format!("block {} pops nothing", digit)
+                    })?;
+                    pairs.push((pushed, digit, offset + check));
+                }
+            }
+        }
+        if !stack.is_empty() {
+            bail!("blocks {:?} are never popped", stack);
+        }
+        Ok(Monad { program, pairs })
+    }
+
+    /// Finds the largest or smallest model number that MONAD accepts.
+    fn model_number(&self, largest: bool) -> Result<i64> {
+        let mut digits = [0; DIGITS];
+        for &(pushed, popped, difference) in &self.pairs {
+            // digits[popped] == digits[pushed] + difference
+            let pushed_digit = if largest {
+                9.min(9 - difference)
+            } else {
+                1.max(1 - difference)
+            };
+            if !(1..=9).contains(&pushed_digit)
+                || !(1..=9).contains(&(pushed_digit + difference))
+            {
+                bail!(
+                    "digits {} and {} cannot differ by {}",
+                    pushed,
+                    popped,
+                    difference
+                );
+            }
+            digits[pushed] = pushed_digit;
+            digits[popped] = pushed_digit + difference;
+        }
+
+        let [_, _, _, z] = run(&self.program, &digits)?;
+        if z != 0 {
+            bail!("MONAD rejects {:?}", digits);
+        }
+        Ok(digits.iter().fold(0, |acc, d| acc * 10 + d))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(lines: &[&str]) -> Vec<Instruction> {
+        lines.iter().map(|line| Instruction::new(line).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_run() {
+        let negate = program(&["inp x", "mul x -1"]);
+        assert_eq!(run(&negate, &[7]).unwrap()[1], -7);
+
+        let is_triple = program(&["inp z", "inp x", "mul z 3", "eql z x"]);
+        assert_eq!(run(&is_triple, &[2, 6]).unwrap()[3], 1);
+        assert_eq!(run(&is_triple, &[2, 7]).unwrap()[3], 0);
+
+        let binary = program(&[
+            "inp w", "add z w", "mod z 2", "div w 2", "add y w", "mod y 2",
+            "div w 2", "add x w", "mod x 2", "div w 2", "mod w 2",
+        ]);
+        assert_eq!(run(&binary, &[11]).unwrap(), [1, 0, 1, 1]);
+        assert!(run(&binary, &[]).is_err());
+    }
+
+    #[test]
+    fn test_model_number() {
+        // Digits are paired as (0, 13), (1, 2), (3, 12), (4, 5), (6, 11), (7, 10), (8, 9)
+        let blocks: [(i64, i64, i64); DIGITS] = [
+            (1, 11, 6),
+            (1, 13, 4),
+            (26, -1, 0),
+            (1, 10, 4),
+            (1, 15, 7),
+            (26, -9, 0),
+            (1, 12, 0),
+            (1, 14, 3),
+            (1, 11, 1),
+            (26, 0, 0),
+            (26, -5, 0),
+            (26, -2, 0),
+            (26, -7, 0),
+            (26, -10, 0),
+        ];
+        let lines = blocks
+            .iter()
+            .flat_map(|&(div, check, offset)| {
+                let numbers = [div, check, offset];
+                let mut numbers = numbers.iter();
+                BLOCK.map(|line| match line.strip_suffix('_') {
+                    Some(prefix) => {
+                        format!("{}{}", prefix, numbers.next().unwrap())
+                    }
+                    None => line.to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let monad = Monad::new(&lines).unwrap();
+        assert_eq!(
+            monad.pairs,
+            vec![
+                (1, 2, 3),
+                (4, 5, -2),
+                (8, 9, 1),
+                (7, 10, -2),
+                (6, 11, -2),
+                (3, 12, -3),
+                (0, 13, -4)
+            ]
+        );
+        assert_eq!(monad.model_number(true).unwrap(), 96999799897765);
+        assert_eq!(monad.model_number(false).unwrap(), 51443133121111);
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Monad;
+
+    fn parse(lines: Vec<String>) -> Result<Monad> {
+        Monad::new(&lines)
+    }
+
+    fn part1(monad: &Monad) -> Result<Answer> {
+        Ok(monad.model_number(true)?.into())
+    }
+
+    fn part2(monad: &Monad) -> Result<Answer> {
+        Ok(monad.model_number(false)?.into())
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+use anyhow::{Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::grid::Grid;
+use crate::util::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    /// Sea cucumber that moves east
+    East,
+    /// Sea cucumber that moves south
+    South,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::East => '>',
+            Cell::South => 'v',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seafloor {
+    cells: Grid<Cell>,
+}
+
+impl Seafloor {
+    fn new(lines: &[String]) -> Result<Seafloor> {
+        let cells = Grid::parse_with(lines, |c| match c {
+            '.' => Some(Cell::Empty),
+            '>' => Some(Cell::East),
+            'v' => Some(Cell::South),
+            _ => None,
+        })?;
+        Ok(Seafloor { cells })
+    }
+
+    /// Moves every sea cucumber of the herd that can move at once.
+    /// Sea cucumbers that leave an edge come back at the opposite edge.
+    /// Returns whether any of them moved.
+    fn move_herd(&mut self, herd: Cell) -> bool {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let ahead = |(x, y): (usize, usize)| match herd {
+            Cell::East => ((x + 1) % width, y),
+            _ => (x, (y + 1) % height),
+        };
+        let movers = self
+            .cells
+            .iter()
+            .filter(|(pos, cell)| {
+                **cell == herd && self.cells[ahead(*pos)] == Cell::Empty
+            })
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        for pos in &movers {
+            self.cells[*pos] = Cell::Empty;
+            self.cells[ahead(*pos)] = herd;
+        }
+        !movers.is_empty()
+    }
+
+    /// Moves the east-facing herd and then the south-facing herd.
+    /// Returns whether any sea cucumber moved.
+    fn step(&mut self) -> bool {
+        let east = self.move_herd(Cell::East);
+        let south = self.move_herd(Cell::South);
+        east || south
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seafloor(lines: &[&str]) -> Seafloor {
+        let lines = lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        Seafloor::new(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_step() {
+        let mut floor = seafloor(&["...>>>>>..."]);
+        floor.step();
+        assert_eq!(floor, seafloor(&["...>>>>.>.."]));
+        floor.step();
+        assert_eq!(floor, seafloor(&["...>>>.>.>."]));
+
+        let mut floor =
+            seafloor(&["..........", ".>v....v..", ".......>..", ".........."]);
+        floor.step();
+        assert_eq!(
+            floor,
+            seafloor(
+                &["..........", ".>........", "..v....v>.", "..........",]
+            )
+        );
+
+        // Wrapping around the edges
+        let mut floor = seafloor(&[
+            "...>...", ".......", "......>", "v.....>", "......>", ".......",
+            "..vvv..",
+        ]);
+        floor.step();
+        assert_eq!(
+            floor,
+            seafloor(&[
+                "..vv>..", ".......", ">......", "v.....>", ">......",
+                ".......", "....v.."
+            ])
+        );
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Seafloor;
+
+    fn parse(lines: Vec<String>) -> Result<Seafloor> {
+        Seafloor::new(&lines).context("could not parse seafloor")
+    }
+
+    /// Part 1: Wait for the sea cucumbers to stop moving
+    fn part1(floor: &Seafloor) -> Result<Answer> {
+        let mut floor = floor.clone();
+        let mut steps = 1;
+        while floor.step() {
+            steps += 1;
+        }
+        Ok(steps.into())
+    }
+
+    /// There is no Part 2 on the last day
+    fn part2(_: &Seafloor) -> Result<Answer> {
+        Ok("Merry Christmas!".into())
+    }
+}
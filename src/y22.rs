@@ -1,4 +1,4 @@
-use anyhow::Result;
+use crate::util::Solver;
 
 mod d01;
 mod d02;
@@ -8,19 +8,49 @@ mod d05;
 mod d06;
 mod d07;
 mod d08;
+mod d09;
+mod d10;
+mod d11;
+mod d12;
+mod d13;
+mod d14;
+mod d15;
+mod d16;
+mod d17;
+mod d18;
+mod d19;
+mod d20;
+mod d21;
+mod d22;
+mod d23;
+mod d24;
+mod d25;
 
-pub fn solver(
-    day: u8,
-) -> fn(Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    match day {
-        1 => d01::solve,
-        2 => d02::solve,
-        3 => d03::solve,
-        4 => d04::solve,
-        5 => d05::solve,
-        6 => d06::solve,
-        7 => d07::solve,
-        8 => d08::solve,
-        _ => todo!("day {}", day),
-    }
-}
+/// Solvers for every implemented day, in increasing order of day.
+pub const SOLVERS: &[(u8, Solver)] = &[
+    (1, Solver::Whole(d01::solve)),
+    (2, Solver::Whole(d02::solve)),
+    (3, Solver::Whole(d03::solve)),
+    (4, Solver::Whole(d04::solve)),
+    (5, Solver::Whole(d05::solve)),
+    (6, Solver::Whole(d06::solve)),
+    (7, Solver::Whole(d07::solve)),
+    (8, Solver::Whole(d08::solve)),
+    (9, Solver::staged::<d09::Day09>()),
+    (10, Solver::staged::<d10::Day10>()),
+    (11, Solver::staged::<d11::Day11>()),
+    (12, Solver::staged::<d12::Day12>()),
+    (13, Solver::staged::<d13::Day13>()),
+    (14, Solver::staged::<d14::Day14>()),
+    (15, Solver::staged::<d15::Day15>()),
+    (16, Solver::staged::<d16::Day16>()),
+    (17, Solver::staged::<d17::Day17>()),
+    (18, Solver::staged::<d18::Day18>()),
+    (19, Solver::staged::<d19::Day19>()),
+    (20, Solver::staged::<d20::Day20>()),
+    (21, Solver::staged::<d21::Day21>()),
+    (22, Solver::staged::<d22::Day22>()),
+    (23, Solver::staged::<d23::Day23>()),
+    (24, Solver::staged::<d24::Day24>()),
+    (25, Solver::staged::<d25::Day25>()),
+];
@@ -21,7 +21,7 @@ pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
     // Part 2: Top three maximum number of calories
     let mut highest = [calories[0], calories[1], calories[2]];
     highest.sort();
-    for &vv in &calories {
+    for &vv in &calories[3..] {
         if vv > highest[0] {
             highest[0] = vv;
             highest.sort();
@@ -1,22 +1,29 @@
 use anyhow::{Context, Result};
 use sscanf::sscanf;
 
+use crate::util::interval::Interval;
+
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let input: Vec<((u32, u32), (u32, u32))> = lines
+    let input: Vec<(Interval<u32>, Interval<u32>)> = lines
         .iter()
         .map(|line| {
             sscanf!(line, "{}-{},{}-{}", u32, u32, u32, u32)
                 .ok()
                 .with_context(|| format!("invalid line {}", line))
-                .map(|(a1, a2, b1, b2)| ((a1, a2), (b1, b2)))
+                .map(|(a1, a2, b1, b2)| {
+                    (
+                        Interval::from_inclusive(a1, a2),
+                        Interval::from_inclusive(b1, b2),
+                    )
+                })
         })
         .collect::<Result<_>>()
         .context("invalid input")?;
 
     // Part 1: Check if one range is "inside" the other
     let mut ans1 = 0;
-    for ((a1, a2), (b1, b2)) in &input {
-        if (a1 <= b1 && a2 >= b2) || (a1 >= b1 && a2 <= b2) {
+    for (a, b) in &input {
+        if a.contains_interval(*b) || b.contains_interval(*a) {
             ans1 += 1;
         }
     }
@@ -24,8 +31,8 @@ pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
 
     // Part 2: Check if the ranges overlap anywhere
     let mut ans2 = 0;
-    for ((a1, a2), (b1, b2)) in &input {
-        if b1 <= a2 && a1 <= b2 {
+    for (a, b) in &input {
+        if a.overlaps(*b) {
             ans2 += 1;
         }
     }
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::geom::{Direction, Point};
+use crate::util::solution::Solution;
+
+/// A Motion moves the head of the rope some steps towards a direction
+#[derive(Debug, Clone, Copy)]
+pub struct Motion {
+    direction: Direction,
+    steps: usize,
+}
+
+impl Motion {
+    fn new(input: &str) -> Result<Motion> {
+        let (direction, steps) = sscanf!(input, "{} {}", char, usize)
+            .ok()
+            .context("expected direction and steps")?;
+        let direction = match direction {
+            'U' => Direction::North,
+            'R' => Direction::East,
+            'D' => Direction::South,
+            'L' => Direction::West,
+            _ => bail!("invalid direction `{}`", direction),
+        };
+        Ok(Motion { direction, steps })
+    }
+}
+
+/// Moves a knot so that it touches the knot ahead of it.
+/// It moves at most one step along each axis, diagonally if needed.
+fn follow(knot: Point, ahead: Point) -> Point {
+    if knot.chebyshev(ahead) <= 1 {
+        return knot;
+    }
+    let delta = ahead - knot;
+    knot + Point::new(delta.x.signum(), delta.y.signum())
+}
+
+/// Counts the positions that the tail of a rope with that many knots visits.
+fn count_tail_positions(motions: &[Motion], knots: usize) -> usize {
+    let mut rope = vec![Point::ORIGIN; knots];
+    let mut visited = HashSet::from([Point::ORIGIN]);
+    for motion in motions {
+        for _ in 0..motion.steps {
+            rope[0] += motion.direction.delta();
+            for ii in 1..knots {
+                rope[ii] = follow(rope[ii], rope[ii - 1]);
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_follow() {
+        let head = Point::new(2, 2);
+        assert_eq!(follow(Point::new(1, 1), head), Point::new(1, 1));
+        assert_eq!(follow(Point::new(2, 2), head), Point::new(2, 2));
+        assert_eq!(follow(Point::new(0, 2), head), Point::new(1, 2));
+        assert_eq!(follow(Point::new(2, 4), head), Point::new(2, 3));
+        assert_eq!(follow(Point::new(1, 4), head), Point::new(2, 3));
+        assert_eq!(follow(Point::new(0, 0), head), Point::new(1, 1));
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Motion>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Motion>> {
+        lines
+            .iter()
+            .map(|line| {
+                Motion::new(line)
+                    .with_context(|| format!("could not parse `{}`", line))
+            })
+            .collect()
+    }
+
+    /// Part 1: The rope has two knots
+    fn part1(motions: &Vec<Motion>) -> Result<Answer> {
+        Ok(count_tail_positions(motions, 2).into())
+    }
+
+    /// Part 2: The rope has ten knots
+    fn part2(motions: &Vec<Motion>) -> Result<Answer> {
+        Ok(count_tail_positions(motions, 10).into())
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::ocr;
+use crate::util::solution::Solution;
+
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+/// Cycles whose signal strengths are summed in Part 1
+const INTERESTING_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+impl Instruction {
+    fn new(input: &str) -> Result<Instruction> {
+        match input.split_once(' ') {
+            None if input == "noop" => Ok(Instruction::Noop),
+            Some(("addx", value)) => Ok(Instruction::Addx(value.parse()?)),
+            _ => bail!("invalid instruction"),
+        }
+    }
+}
+
+/// Value of the X register during each cycle, starting from the first cycle
+fn register_values(program: &[Instruction]) -> Vec<i64> {
+    let mut x = 1;
+    let mut result = vec![];
+    for instruction in program {
+        match instruction {
+            Instruction::Noop => result.push(x),
+            Instruction::Addx(value) => {
+                result.extend([x, x]);
+                x += value;
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_values() {
+        let program =
+            [Instruction::Noop, Instruction::Addx(3), Instruction::Addx(-5)];
+        assert_eq!(register_values(&program), vec![1, 1, 1, 4, 4]);
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Instruction>> {
+        lines
+            .iter()
+            .map(|line| {
+                Instruction::new(line)
+                    .with_context(|| format!("could not parse `{}`", line))
+            })
+            .collect()
+    }
+
+    /// Part 1: Sum the signal strengths during the 20th, 60th, ... cycles
+    fn part1(program: &Vec<Instruction>) -> Result<Answer> {
+        let values = register_values(program);
+        if values.len() < 220 {
+            bail!("program runs for only {} cycles", values.len());
+        }
+        let strength = INTERESTING_CYCLES
+            .iter()
+            .map(|&cycle| cycle as i64 * values[cycle - 1])
+            .sum::<i64>();
+        Ok(strength.into())
+    }
+
+    /// Part 2: Draw the sprite at X onto the CRT
+    fn part2(program: &Vec<Instruction>) -> Result<Answer> {
+        let values = register_values(program);
+        if values.len() < CRT_WIDTH * CRT_HEIGHT {
+            bail!(
+                "program runs for {} cycles, but the CRT needs {}",
+                values.len(),
+                CRT_WIDTH * CRT_HEIGHT
+            );
+        }
+        let pixels = values[..CRT_WIDTH * CRT_HEIGHT]
+            .chunks(CRT_WIDTH)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(x, sprite)| (x as i64 - sprite).abs() <= 1)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let text = ocr::recognize(&pixels);
+        Ok(Answer::Grid { pixels, text })
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{bail, Context, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+use crate::util::vectors::group;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    fn new(input: &str) -> Result<Operation> {
+        match sscanf!(input, "new = old {} {}", char, str) {
+            Ok(('*', "old")) => Ok(Operation::Square),
+            Ok(('*', value)) => Ok(Operation::Multiply(value.parse()?)),
+            Ok(('+', value)) => Ok(Operation::Add(value.parse()?)),
+            _ => bail!("invalid operation `{}`", input),
+        }
+    }
+
+    fn apply(&self, worry: u64) -> u64 {
+        match self {
+            Operation::Add(value) => worry + value,
+            Operation::Multiply(value) => worry * value,
+            Operation::Square => worry * worry,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    /// Monkeys to throw to if the item is divisible by divisor, and if it is not
+    targets: (usize, usize),
+}
+
+impl Monkey {
+    fn new(lines: &[String]) -> Result<Monkey> {
+        let lines = lines.iter().map(|line| line.trim()).collect::<Vec<_>>();
+        let [_, items, operation, divisor, if_true, if_false] = lines[..]
+        else {
+            bail!("expected 6 lines, got {}", lines.len());
+        };
+        let items = items
+            .strip_prefix("Starting items: ")
+            .context("expected starting items")?
+            .split(", ")
+            .map(|item| item.parse())
+            .collect::<Result<Vec<_>, _>>()
+            .context("could not parse items")?;
+        let operation = Operation::new(
+            operation
+                .strip_prefix("Operation: ")
+                .context("expected operation")?,
+        )?;
+        let divisor = sscanf!(divisor, "Test: divisible by {}", u64)
+            .ok()
+            .context("expected test")?;
+        let targets = (
+            sscanf!(if_true, "If true: throw to monkey {}", usize)
+                .ok()
+                .context("expected target if true")?,
+            sscanf!(if_false, "If false: throw to monkey {}", usize)
+                .ok()
+                .context("expected target if false")?,
+        );
+        Ok(Monkey { items, operation, divisor, targets })
+    }
+}
+
+/// Plays some rounds of keep away, where relief divides the worry level
+/// after each inspection. Returns how many items each monkey inspected.
+/// Without relief, worry levels are kept modulo the product of all divisors,
+/// which keeps whether they are divisible by each divisor.
+fn play(monkeys: &[Monkey], rounds: usize, relief: u64) -> Vec<usize> {
+    let mut monkeys = monkeys.to_vec();
+    let modulus = monkeys.iter().map(|monkey| monkey.divisor).product::<u64>();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for ii in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[ii].items);
+            inspections[ii] += items.len();
+            let Monkey { operation, divisor, targets, .. } = monkeys[ii];
+            for item in items {
+                let item = match relief {
+                    1 => operation.apply(item) % modulus,
+                    _ => operation.apply(item) / relief,
+                };
+                let target =
+                    if item % divisor == 0 { targets.0 } else { targets.1 };
+                monkeys[target].items.push(item);
+            }
+        }
+    }
+    inspections
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: u64) -> usize {
+    let mut inspections = play(monkeys, rounds, relief);
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    inspections.iter().take(2).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation() {
+        assert_eq!(Operation::new("new = old * old").unwrap().apply(7), 49);
+        assert_eq!(Operation::new("new = old * 19").unwrap().apply(2), 38);
+        assert_eq!(Operation::new("new = old + 6").unwrap().apply(2), 8);
+        assert!(Operation::new("new = old - 6").is_err());
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Monkey>> {
+        let monkeys = group(lines)
+            .iter()
+            .enumerate()
+            .map(|(index, lines)| {
+                Monkey::new(lines).with_context(|| {
+                    format!("could not parse monkey {}", index)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (index, monkey) in monkeys.iter().enumerate() {
+            let (a, b) = monkey.targets;
+            if a >= monkeys.len()
+                || b >= monkeys.len()
+                || a == index
+                || b == index
+            {
+                bail!("monkey {} throws to invalid monkeys", index);
+            }
+        }
+        Ok(monkeys)
+    }
+
+    /// Part 1: Worry levels are divided by three after each inspection
+    fn part1(monkeys: &Vec<Monkey>) -> Result<Answer> {
+        Ok(monkey_business(monkeys, 20, 3).into())
+    }
+
+    /// Part 2: Worry levels are no longer divided
+    fn part2(monkeys: &Vec<Monkey>) -> Result<Answer> {
+        Ok(monkey_business(monkeys, 10000, 1).into())
+    }
+}
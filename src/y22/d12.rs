@@ -0,0 +1,92 @@
+use anyhow::{Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::grid::Grid;
+use crate::util::search;
+use crate::util::solution::Solution;
+
+pub struct Heightmap {
+    heights: Grid<u8>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Heightmap {
+    fn new(lines: &[String]) -> Result<Heightmap> {
+        let cells = Grid::parse(lines)?;
+        let start = cells.find(|c| *c == 'S').context("no start")?;
+        let end = cells.find(|c| *c == 'E').context("no end")?;
+        let heights = Grid::parse_with(lines, |c| match c {
+            'S' => Some(0),
+            'E' => Some(b'z' - b'a'),
+            'a'..='z' => Some(c as u8 - b'a'),
+            _ => None,
+        })?;
+        Ok(Heightmap { heights, start, end })
+    }
+
+    /// Fewest steps from each position to the end,
+    /// where each step climbs at most one higher.
+    /// Positions that cannot reach the end are left out.
+    fn steps_to_end(&self) -> Grid<Option<usize>> {
+        // Walks down from the end, which is the same as climbing up to it
+        let distances = search::bfs_all_distances(self.end, |&pos| {
+            self.heights
+                .neighbors4(pos)
+                .filter(|&next| self.heights[next] + 1 >= self.heights[pos])
+                .collect::<Vec<_>>()
+        });
+        Grid::from_fn(self.heights.width(), self.heights.height(), |pos| {
+            distances.get(&pos).copied()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_to_end() {
+        let lines =
+            ["SbcdefghijklmnopqrstuvwxyE", "aaaaaaaaaaaaaaaaaaaaaaaaaz"]
+                .map(|s| s.to_string());
+        let steps = Heightmap::new(&lines).unwrap().steps_to_end();
+        assert_eq!(steps[(0, 0)], Some(25));
+        assert_eq!(steps[(24, 0)], Some(1));
+        assert_eq!(steps[(25, 1)], Some(1));
+        // Too low to climb onto y or z, so has to walk back to b
+        assert_eq!(steps[(24, 1)], Some(23 + 1 + 24));
+        assert_eq!(steps[(0, 1)], Some(26));
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+
+    fn parse(lines: Vec<String>) -> Result<Heightmap> {
+        Heightmap::new(&lines).context("could not parse heightmap")
+    }
+
+    /// Part 1: Climb from the start to the end
+    fn part1(map: &Heightmap) -> Result<Answer> {
+        let steps = map.steps_to_end()[map.start]
+            .context("cannot reach the end from the start")?;
+        Ok(steps.into())
+    }
+
+    /// Part 2: Climb from any of the lowest positions
+    fn part2(map: &Heightmap) -> Result<Answer> {
+        let steps = map.steps_to_end();
+        let fewest = map
+            .heights
+            .iter()
+            .filter(|(_, height)| **height == 0)
+            .filter_map(|(pos, _)| steps[pos])
+            .min()
+            .context("cannot reach the end from the lowest positions")?;
+        Ok(fewest.into())
+    }
+}
@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+use crate::util::vectors::group;
+
+/// A Packet is either an integer, or a list of Packets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn new(input: &str) -> Result<Packet> {
+        let mut chars = input.chars().peekable();
+        let packet = Packet::parse(&mut chars)?;
+        if let Some(c) = chars.next() {
+            bail!("unexpected `{}` after packet", c);
+        }
+        Ok(packet)
+    }
+
+    fn parse(
+        chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+    ) -> Result<Packet> {
+        match chars.next() {
+            Some('[') => {
+                let mut list = vec![];
+                if chars.peek() == Some(&']') {
+                    chars.next();
+                    return Ok(Packet::List(list));
+                }
+                loop {
+                    list.push(Packet::parse(chars)?);
+                    match chars.next() {
+                        Some(',') => continue,
+                        Some(']') => return Ok(Packet::List(list)),
+                        c => bail!("expected `,` or `]`, got {:?}", c),
+                    }
+                }
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let mut value = c.to_digit(10).unwrap();
+                while let Some(digit) =
+                    chars.peek().and_then(|c| c.to_digit(10))
+                {
+                    value = value * 10 + digit;
+                    chars.next();
+                }
+                Ok(Packet::Integer(value))
+            }
+            c => bail!("expected `[` or a digit, got {:?}", c),
+        }
+    }
+}
+
+/// Packets are in the right order if the left one is less than the right one
+impl Ord for Packet {
+    fn cmp(&self, other: &Packet) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(a), Packet::Integer(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            (Packet::Integer(a), Packet::List(_)) => {
+                Packet::List(vec![Packet::Integer(*a)]).cmp(other)
+            }
+            (Packet::List(_), Packet::Integer(b)) => {
+                self.cmp(&Packet::List(vec![Packet::Integer(*b)]))
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Packet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(input: &str) -> Packet {
+        Packet::new(input).unwrap()
+    }
+
+    #[test]
+    fn test_compare() {
+        assert!(packet("[1,1,3,1,1]") < packet("[1,1,5,1,1]"));
+        assert!(packet("[[1],[2,3,4]]") < packet("[[1],4]"));
+        assert!(packet("[9]") > packet("[[8,7,6]]"));
+        assert!(packet("[[4,4],4,4]") < packet("[[4,4],4,4,4]"));
+        assert!(packet("[7,7,7,7]") > packet("[7,7,7]"));
+        assert!(packet("[]") < packet("[3]"));
+        assert!(packet("[[[]]]") > packet("[[]]"));
+        assert!(
+            packet("[1,[2,[3,[4,[5,6,7]]]],8,9]")
+                > packet("[1,[2,[3,[4,[5,6,0]]]],8,9]")
+        );
+        assert_eq!(
+            packet("[10,[]]"),
+            Packet::List(vec![Packet::Integer(10), Packet::List(vec![])])
+        );
+        assert!(Packet::new("[1,2").is_err());
+        assert!(Packet::new("[1]]").is_err());
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<(Packet, Packet)>> {
+        let parse_pair = |pair: &[String]| match pair {
+            [left, right] => Ok((Packet::new(left)?, Packet::new(right)?)),
+            _ => bail!("expected 2 packets, got {}", pair.len()),
+        };
+        group(lines)
+            .iter()
+            .enumerate()
+            .map(|(index, pair)| {
+                parse_pair(pair).with_context(|| {
+                    format!("could not parse pair {}", index + 1)
+                })
+            })
+            .collect()
+    }
+
+    /// Part 1: Find the pairs that are in the right order
+    fn part1(pairs: &Vec<(Packet, Packet)>) -> Result<Answer> {
+        let sum = pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(index, _)| index + 1)
+            .sum::<usize>();
+        Ok(sum.into())
+    }
+
+    /// Part 2: Sort all packets along with two divider packets
+    fn part2(pairs: &Vec<(Packet, Packet)>) -> Result<Answer> {
+        // Only the number of packets before each divider matters
+        let dividers = [Packet::new("[[2]]")?, Packet::new("[[6]]")?];
+        let key = dividers
+            .iter()
+            .enumerate()
+            .map(|(index, divider)| {
+                let before = pairs
+                    .iter()
+                    .flat_map(|(left, right)| [left, right])
+                    .filter(|packet| *packet < divider)
+                    .count();
+                before + index + 1
+            })
+            .product::<usize>();
+        Ok(key.into())
+    }
+}
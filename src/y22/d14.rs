@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::geom::Point;
+use crate::util::solution::Solution;
+
+/// Where the sand pours in from
+const SOURCE: Point = Point::new(500, 0);
+
+/// A Cave keeps track of every tile that is blocked by rock or by sand
+#[derive(Debug, Clone)]
+pub struct Cave {
+    blocked: HashSet<Point>,
+    /// y of the lowest rock
+    lowest: i64,
+}
+
+impl Cave {
+    fn new(lines: &[String]) -> Result<Cave> {
+        let mut blocked = HashSet::new();
+        for line in lines {
+            let path = line
+                .split(" -> ")
+                .map(|point| {
+                    sscanf!(point, "{},{}", i64, i64)
+                        .ok()
+                        .map(|(x, y)| Point::new(x, y))
+                        .with_context(|| format!("invalid point `{}`", point))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if path.len() == 1 {
+                blocked.insert(path[0]);
+            }
+            for pair in path.windows(2) {
+                let (from, to) = (pair[0], pair[1]);
+                if from.x != to.x && from.y != to.y {
+                    bail!("rock from {} to {} is not straight", from, to);
+                }
+                let step = Point::new(
+                    (to.x - from.x).signum(),
+                    (to.y - from.y).signum(),
+                );
+                let mut point = from;
+                blocked.insert(point);
+                while point != to {
+                    point += step;
+                    blocked.insert(point);
+                }
+            }
+        }
+        let lowest = blocked
+            .iter()
+            .map(|point| point.y)
+            .max()
+            .context("there is no rock")?;
+        Ok(Cave { blocked, lowest })
+    }
+
+    /// Drops a unit of sand from the source until it comes to rest,
+    /// and returns where it rests.
+    /// With a floor, the floor is two below the lowest rock.
+    /// Without one, returns None if the sand falls below the lowest rock.
+    fn drop_sand(&self, has_floor: bool) -> Option<Point> {
+        let mut sand = SOURCE;
+        loop {
+            if sand.y == self.lowest + 1 {
+                return if has_floor { Some(sand) } else { None };
+            }
+            let next = [0, -1, 1]
+                .map(|dx| sand + Point::new(dx, 1))
+                .into_iter()
+                .find(|next| !self.blocked.contains(next));
+            match next {
+                Some(next) => sand = next,
+                None => return Some(sand),
+            }
+        }
+    }
+
+    /// Pours sand until it falls into the abyss, or until it blocks the source.
+    /// Returns how many units of sand came to rest.
+    fn pour(&self, has_floor: bool) -> usize {
+        let mut cave = self.clone();
+        let mut count = 0;
+        while let Some(sand) = cave.drop_sand(has_floor) {
+            cave.blocked.insert(sand);
+            count += 1;
+            if sand == SOURCE {
+                break;
+            }
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pour() {
+        // Only one unit fits on the shelf before the rest slide off
+        let lines = ["499,3 -> 501,3"].map(|s| s.to_string());
+        let cave = Cave::new(&lines).unwrap();
+        assert_eq!(cave.lowest, 3);
+        assert_eq!(cave.pour(false), 1);
+        // A pyramid five rows high, except for the shelf and the tile under it
+        assert_eq!(cave.pour(true), 1 + 3 + 5 + 7 + 9 - 3 - 1);
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+
+    fn parse(lines: Vec<String>) -> Result<Cave> {
+        Cave::new(&lines).context("could not parse cave")
+    }
+
+    /// Part 1: Sand falls into the abyss below the rocks
+    fn part1(cave: &Cave) -> Result<Answer> {
+        Ok(cave.pour(false).into())
+    }
+
+    /// Part 2: Sand rests on a floor below the rocks
+    fn part2(cave: &Cave) -> Result<Answer> {
+        Ok(cave.pour(true).into())
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use itertools::iproduct;
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::geom::Point;
+use crate::util::interval::{Interval, IntervalSet};
+use crate::util::solution::Solution;
+
+/// Row to check in Part 1
+const ROW: i64 = 2000000;
+/// The distress beacon's coordinates are between 0 and this in Part 2
+const MAX_COORDINATE: i64 = 4000000;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sensor {
+    position: Point,
+    beacon: Point,
+}
+
+impl Sensor {
+    fn new(input: &str) -> Result<Sensor> {
+        let (sx, sy, bx, by) = sscanf!(
+            input,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            i64,
+            i64,
+            i64,
+            i64
+        )
+        .ok()
+        .context("expected sensor and beacon")?;
+        Ok(Sensor { position: Point::new(sx, sy), beacon: Point::new(bx, by) })
+    }
+
+    /// The sensor sees every position within this distance,
+    /// so no other beacon can be there.
+    fn radius(&self) -> i64 {
+        self.position.manhattan(self.beacon)
+    }
+
+    fn covers(&self, point: Point) -> bool {
+        self.position.manhattan(point) <= self.radius()
+    }
+
+    /// Positions on row y that the sensor covers
+    fn coverage(&self, y: i64) -> Interval<i64> {
+        let half_width = self.radius() - (self.position.y - y).abs();
+        Interval::from_inclusive(
+            self.position.x - half_width,
+            self.position.x + half_width,
+        )
+    }
+}
+
+/// Counts the positions on row y where there cannot be a beacon
+fn count_impossible(sensors: &[Sensor], y: i64) -> i64 {
+    let covered = sensors
+        .iter()
+        .map(|sensor| sensor.coverage(y))
+        .collect::<IntervalSet<_>>();
+    let beacons = sensors
+        .iter()
+        .map(|sensor| sensor.beacon)
+        .filter(|beacon| beacon.y == y && covered.contains(beacon.x))
+        .collect::<HashSet<_>>();
+    covered.len() - beacons.len() as i64
+}
+
+/// Finds the only position where x and y are between 0 and max_coordinate,
+/// and that no sensor covers.
+/// Such a position is just outside the edges of some sensors,
+/// so it is where those edges, which are diagonal lines, cross.
+fn find_distress_beacon(
+    sensors: &[Sensor],
+    max_coordinate: i64,
+) -> Option<Point> {
+    // Lines y = x + c, and lines y = -x + c, just outside of each sensor
+    let mut rising = HashSet::new();
+    let mut falling = HashSet::new();
+    for sensor in sensors {
+        let (Point { x, y }, r) = (sensor.position, sensor.radius() + 1);
+        rising.extend([y - x - r, y - x + r]);
+        falling.extend([y + x - r, y + x + r]);
+    }
+    let range = 0..=max_coordinate;
+    iproduct!(&rising, &falling)
+        .filter(|(a, b)| (*a + *b) % 2 == 0)
+        .map(|(a, b)| Point::new((b - a) / 2, (a + b) / 2))
+        .filter(|p| range.contains(&p.x) && range.contains(&p.y))
+        .find(|p| sensors.iter().all(|sensor| !sensor.covers(*p)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    // The example checks a different row and area from the puzzle input
+    #[test]
+    fn test_example() {
+        let sensors = EXAMPLE
+            .lines()
+            .map(|line| Sensor::new(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(count_impossible(&sensors, 10), 26);
+        assert_eq!(
+            find_distress_beacon(&sensors, 20),
+            Some(Point::new(14, 11))
+        );
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Sensor>> {
+        lines
+            .iter()
+            .map(|line| {
+                Sensor::new(line)
+                    .with_context(|| format!("could not parse `{}`", line))
+            })
+            .collect()
+    }
+
+    /// Part 1: Find where the beacon cannot be on one row
+    fn part1(sensors: &Vec<Sensor>) -> Result<Answer> {
+        Ok(count_impossible(sensors, ROW).into())
+    }
+
+    /// Part 2: Find the one position that no sensor covers
+    fn part2(sensors: &Vec<Sensor>) -> Result<Answer> {
+        let beacon = find_distress_beacon(sensors, MAX_COORDINATE)
+            .context("could not find the distress beacon")?;
+        Ok((beacon.x * 4000000 + beacon.y).into())
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::search;
+use crate::util::solution::Solution;
+
+const START: &str = "AA";
+/// Most valves worth opening, as every set of them is kept track of
+const MAX_USEFUL: usize = 20;
+
+/// The valves that are worth opening, and how far apart they are
+pub struct Volcano {
+    /// Flow rate of each valve worth opening
+    rates: Vec<u32>,
+    /// Minutes to walk between two valves worth opening
+    distances: Vec<Vec<u32>>,
+    /// Minutes to walk from the start to each valve worth opening
+    from_start: Vec<u32>,
+}
+
+impl Volcano {
+    fn new(lines: &[String]) -> Result<Volcano> {
+        let mut valves = HashMap::new();
+        for line in lines {
+            let (name, rate, tunnels) = sscanf!(
+                line,
+                "Valve {} has flow rate={}; {} {} to {} {}",
+                String,
+                u32,
+                str,
+                str,
+                str,
+                str
+            )
+            .ok()
+            .map(|(name, rate, _, _, _, tunnels)| (name, rate, tunnels))
+            .with_context(|| format!("could not parse `{}`", line))?;
+            let tunnels =
+                tunnels.split(", ").map(|s| s.to_string()).collect::<Vec<_>>();
+            valves.insert(name, (rate, tunnels));
+        }
+        if !valves.contains_key(START) {
+            bail!("there is no valve {}", START);
+        }
+        for (name, (_, tunnels)) in &valves {
+            if let Some(tunnel) =
+                tunnels.iter().find(|t| !valves.contains_key(*t))
+            {
+                bail!("valve {} leads to unknown valve {}", name, tunnel);
+            }
+        }
+
+        let mut useful = valves
+            .iter()
+            .filter(|(_, (rate, _))| *rate > 0)
+            .map(|(name, (rate, _))| (name.clone(), *rate))
+            .collect::<Vec<_>>();
+        useful.sort();
+        if useful.len() > MAX_USEFUL {
+            bail!("too many valves worth opening: {}", useful.len());
+        }
+        let distances_from = |from: &str| {
+            let distances =
+                search::bfs_all_distances(from.to_string(), |name: &String| {
+                    valves[name].1.clone()
+                });
+            useful
+                .iter()
+                .map(|(to, _)| distances.get(to).map(|d| *d as u32))
+                .collect::<Option<Vec<_>>>()
+                .with_context(|| {
+                    format!("some valves cannot be reached from {}", from)
+                })
+        };
+        let distances = useful
+            .iter()
+            .map(|(name, _)| distances_from(name))
+            .collect::<Result<Vec<_>>>()?;
+        let from_start = distances_from(START)?;
+        let rates = useful.iter().map(|(_, rate)| *rate).collect();
+        Ok(Volcano { rates, distances, from_start })
+    }
+
+    /// Most pressure that can be released in some minutes, for each set of opened valves.
+    /// The set is a bitmask of the valves worth opening.
+    fn most_pressure(&self, minutes: u32) -> Vec<u32> {
+        let mut result = vec![0; 1 << self.rates.len()];
+        for valve in 0..self.rates.len() {
+            self.explore(
+                valve,
+                self.from_start[valve],
+                minutes,
+                0,
+                0,
+                &mut result,
+            );
+        }
+        result
+    }
+
+    /// Walks to valve, taking elapsed minutes, and opens it if there is time,
+    /// then explores every valve that is still closed.
+    fn explore(
+        &self,
+        valve: usize,
+        elapsed: u32,
+        minutes: u32,
+        opened: usize,
+        pressure: u32,
+        result: &mut [u32],
+    ) {
+        // One more minute to open the valve
+        if elapsed + 1 >= minutes {
+            return;
+        }
+        let remaining = minutes - elapsed - 1;
+        let opened = opened | 1 << valve;
+        let pressure = pressure + remaining * self.rates[valve];
+        result[opened] = result[opened].max(pressure);
+        for next in 0..self.rates.len() {
+            if opened & 1 << next == 0 {
+                let elapsed = elapsed + 1 + self.distances[valve][next];
+                self.explore(next, elapsed, minutes, opened, pressure, result);
+            }
+        }
+    }
+}
+
+/// Most pressure two can release in some minutes, if they open different valves
+fn most_pressure_together(volcano: &Volcano, minutes: u32) -> u32 {
+    let alone = volcano.most_pressure(minutes);
+    // within[set] is the most pressure from opening only valves within the set
+    let mut within = alone.clone();
+    for bit in 0..volcano.rates.len() {
+        for set in 0..within.len() {
+            if set & 1 << bit != 0 {
+                within[set] = within[set].max(within[set ^ 1 << bit]);
+            }
+        }
+    }
+    let all = within.len() - 1;
+    (0..alone.len())
+        .map(|set| alone[set] + within[all ^ set])
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_most_pressure() {
+        let lines = [
+            "Valve AA has flow rate=0; tunnels lead to valves BB, CC",
+            "Valve BB has flow rate=10; tunnel leads to valve AA",
+            "Valve CC has flow rate=1; tunnels lead to valves AA, DD",
+            "Valve DD has flow rate=100; tunnel leads to valve CC",
+        ]
+        .map(|s| s.to_string());
+        let volcano = Volcano::new(&lines).unwrap();
+        assert_eq!(volcano.rates, vec![10, 1, 100]);
+        assert_eq!(volcano.from_start, vec![1, 1, 2]);
+        // Skip CC and open DD first, then come back for BB
+        let best = volcano.most_pressure(10);
+        assert_eq!(best[0b101], 7 * 100 + 3 * 10);
+        assert_eq!(best.iter().max(), Some(&(7 * 100 + 3 * 10)));
+        // One opens BB and then CC, and the other opens DD
+        assert_eq!(most_pressure_together(&volcano, 10), 8 * 10 + 5 + 7 * 100);
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Volcano;
+
+    fn parse(lines: Vec<String>) -> Result<Volcano> {
+        Volcano::new(&lines)
+    }
+
+    /// Part 1: Open valves alone for 30 minutes
+    fn part1(volcano: &Volcano) -> Result<Answer> {
+        let best = volcano.most_pressure(30);
+        Ok((*best.iter().max().unwrap()).into())
+    }
+
+    /// Part 2: Open valves with an elephant for 26 minutes
+    fn part2(volcano: &Volcano) -> Result<Answer> {
+        Ok(most_pressure_together(volcano, 26).into())
+    }
+}
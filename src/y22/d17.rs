@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+const WIDTH: usize = 7;
+
+/// Rows of each rock from the bottom, where bit x is set if column x is rock
+const ROCKS: [&[u16]; 5] = [
+    &[0b1111],
+    &[0b010, 0b111, 0b010],
+    &[0b111, 0b100, 0b100],
+    &[0b1, 0b1, 0b1, 0b1],
+    &[0b11, 0b11],
+];
+
+/// A Chamber is where the rocks fall, pushed around by jets of hot gas
+struct Chamber<'a> {
+    /// Rows from the bottom, where bit x is set if column x is rock
+    rows: Vec<u16>,
+    /// Pushes to the left (-1) or to the right (1)
+    jets: &'a [i64],
+    /// Index of the next jet
+    jet: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [i64]) -> Chamber<'a> {
+        Chamber { rows: vec![], jets, jet: 0 }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether rock fits with its bottom left corner at (x, y)
+    fn fits(&self, rock: &[u16], x: i64, y: usize) -> bool {
+        if x < 0 {
+            return false;
+        }
+        rock.iter().enumerate().all(|(dy, row)| {
+            let row = row << x;
+            row < 1 << WIDTH
+                && self.rows.get(y + dy).is_none_or(|r| r & row == 0)
+        })
+    }
+
+    /// Drops a rock until it comes to rest
+    fn drop(&mut self, rock: &[u16]) {
+        let (mut x, mut y) = (2, self.height() + 3);
+        loop {
+            let push = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            if self.fits(rock, x + push, y) {
+                x += push;
+            }
+            if y == 0 || !self.fits(rock, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+        for (dy, row) in rock.iter().enumerate() {
+            if y + dy >= self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[y + dy] |= row << x;
+        }
+    }
+
+    /// Empty cells that a falling rock can still reach, by row from the top,
+    /// down to the last row with any.
+    /// Rocks never move up, so each row is reached from the row above it
+    /// and then by moving sideways.
+    fn surface(&self) -> Vec<u16> {
+        let full = (1 << WIDTH) - 1;
+        let mut reach = full;
+        let mut surface = vec![];
+        for row in self.rows.iter().rev() {
+            let empty = !row & full;
+            reach &= empty;
+            loop {
+                let spread = (reach | reach << 1 | reach >> 1) & empty;
+                if spread == reach {
+                    break;
+                }
+                reach = spread;
+            }
+            if reach == 0 {
+                break;
+            }
+            surface.push(reach);
+        }
+        surface
+    }
+}
+
+/// Height of the tower after some rocks have fallen.
+/// Rocks only land on the surface, so once the rock, the jet, and the surface
+/// are the same as before, the tower repeats from there,
+/// and the rest of the tower is extrapolated from the repeating part.
+fn tower_height(jets: &[i64], rocks: u64) -> u64 {
+    let mut chamber = Chamber::new(jets);
+    // When each state was last seen, and how high the tower was then
+    let mut seen = HashMap::new();
+    let mut skipped_height = 0;
+    let mut count = 0;
+    while count < rocks {
+        let rock = (count % ROCKS.len() as u64) as usize;
+        chamber.drop(ROCKS[rock]);
+        count += 1;
+
+        let height = chamber.height() as u64;
+        if skipped_height > 0 {
+            continue;
+        }
+        let state = (rock, chamber.jet, chamber.surface());
+        if let Some((prev_count, prev_height)) =
+            seen.insert(state, (count, height))
+        {
+            let period = count - prev_count;
+            let cycles = (rocks - count) / period;
+            count += cycles * period;
+            skipped_height = cycles * (height - prev_height);
+        }
+    }
+    chamber.height() as u64 + skipped_height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop() {
+        // Always pushed to the right
+        let jets = [1];
+        let mut chamber = Chamber::new(&jets);
+        chamber.drop(ROCKS[0]);
+        assert_eq!(chamber.rows, vec![0b1111000]);
+        chamber.drop(ROCKS[1]);
+        assert_eq!(
+            chamber.rows,
+            vec![0b1111000, 0b0100000, 0b1110000, 0b0100000]
+        );
+        // The cell under the right arm of the plus is shut off
+        assert_eq!(
+            chamber.surface(),
+            vec![0b1011111, 0b0001111, 0b0011111, 0b0000111]
+        );
+    }
+
+    #[test]
+    fn test_tower_height() {
+        let jets = [1, 1, -1, 1, -1, -1, -1];
+        let mut chamber = Chamber::new(&jets);
+        for count in 0..5000 {
+            chamber.drop(ROCKS[count % ROCKS.len()]);
+        }
+        assert_eq!(tower_height(&jets, 5000), chamber.height() as u64);
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<i64>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<i64>> {
+        if lines.len() != 1 {
+            bail!("expected 1 line, got {}", lines.len());
+        }
+        let jets = lines[0]
+            .chars()
+            .map(|c| match c {
+                '<' => Ok(-1),
+                '>' => Ok(1),
+                _ => bail!("invalid jet `{}`", c),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if jets.is_empty() {
+            bail!("there are no jets");
+        }
+        Ok(jets)
+    }
+
+    /// Part 1: Drop 2022 rocks
+    fn part1(jets: &Vec<i64>) -> Result<Answer> {
+        Ok(tower_height(jets, 2022).into())
+    }
+
+    /// Part 2: Drop a trillion rocks
+    fn part2(jets: &Vec<i64>) -> Result<Answer> {
+        Ok(tower_height(jets, 1000000000000).into())
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::geom::Point3;
+use crate::util::search;
+use crate::util::solution::Solution;
+
+/// Counts the sides of cubes that do not touch another cube
+fn surface_area(cubes: &HashSet<Point3>) -> usize {
+    cubes
+        .iter()
+        .flat_map(|cube| cube.neighbors6())
+        .filter(|side| !cubes.contains(side))
+        .count()
+}
+
+/// Counts the sides of cubes that steam outside of the droplet can reach.
+/// Steam spreads from a corner of a box around the droplet.
+fn exterior_surface_area(cubes: &HashSet<Point3>) -> usize {
+    let min =
+        |f: fn(&Point3) -> i64| cubes.iter().map(f).min().unwrap_or(0) - 1;
+    let max =
+        |f: fn(&Point3) -> i64| cubes.iter().map(f).max().unwrap_or(0) + 1;
+    let (lo, hi) = (
+        Point3::new(min(|p| p.x), min(|p| p.y), min(|p| p.z)),
+        Point3::new(max(|p| p.x), max(|p| p.y), max(|p| p.z)),
+    );
+    let in_box = |p: &Point3| {
+        (lo.x..=hi.x).contains(&p.x)
+            && (lo.y..=hi.y).contains(&p.y)
+            && (lo.z..=hi.z).contains(&p.z)
+    };
+    let steam = search::bfs_all_distances(lo, |p| {
+        p.neighbors6()
+            .into_iter()
+            .filter(|next| in_box(next) && !cubes.contains(next))
+            .collect::<Vec<_>>()
+    });
+    cubes
+        .iter()
+        .flat_map(|cube| cube.neighbors6())
+        .filter(|side| steam.contains_key(side))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_surface_area() {
+        let cubes = HashSet::from([Point3::new(1, 1, 1), Point3::new(2, 1, 1)]);
+        assert_eq!(surface_area(&cubes), 10);
+        assert_eq!(exterior_surface_area(&cubes), 10);
+
+        // A 3x3x3 cube with a hole in the middle
+        let mut cubes = HashSet::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                for z in 0..3 {
+                    cubes.insert(Point3::new(x, y, z));
+                }
+            }
+        }
+        cubes.remove(&Point3::new(1, 1, 1));
+        assert_eq!(surface_area(&cubes), 6 * 9 + 6);
+        assert_eq!(exterior_surface_area(&cubes), 6 * 9);
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<Point3>;
+
+    fn parse(lines: Vec<String>) -> Result<HashSet<Point3>> {
+        let cubes = lines
+            .iter()
+            .map(|line| {
+                sscanf!(line, "{},{},{}", i64, i64, i64)
+                    .ok()
+                    .map(|(x, y, z)| Point3::new(x, y, z))
+                    .with_context(|| format!("invalid cube `{}`", line))
+            })
+            .collect::<Result<HashSet<_>>>()?;
+        if cubes.is_empty() {
+            bail!("there are no cubes");
+        }
+        Ok(cubes)
+    }
+
+    /// Part 1: Count every side that is not covered by another cube
+    fn part1(cubes: &HashSet<Point3>) -> Result<Answer> {
+        Ok(surface_area(cubes).into())
+    }
+
+    /// Part 2: Leave out the air pockets trapped inside the droplet
+    fn part2(cubes: &HashSet<Point3>) -> Result<Answer> {
+        Ok(exterior_surface_area(cubes).into())
+    }
+}
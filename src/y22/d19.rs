@@ -0,0 +1,166 @@
+use anyhow::{Context, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+/// Resources are ore, clay, obsidian and geodes, in that order
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blueprint {
+    id: u32,
+    /// How much of each resource a robot that collects some resource costs
+    costs: [[u32; 4]; 4],
+}
+
+impl Blueprint {
+    fn new(input: &str) -> Result<Blueprint> {
+        let (id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) = sscanf!(
+            input,
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            u32, u32, u32, u32, u32, u32, u32
+        )
+        .ok()
+        .context("expected a blueprint")?;
+        let mut costs = [[0; 4]; 4];
+        costs[ORE][ORE] = ore;
+        costs[CLAY][ORE] = clay;
+        costs[OBSIDIAN][ORE] = obsidian_ore;
+        costs[OBSIDIAN][CLAY] = obsidian_clay;
+        costs[GEODE][ORE] = geode_ore;
+        costs[GEODE][OBSIDIAN] = geode_obsidian;
+        Ok(Blueprint { id, costs })
+    }
+
+    /// Most geodes that can be opened within some minutes,
+    /// starting with a single ore-collecting robot.
+    fn most_geodes(&self, minutes: u32) -> u32 {
+        // Resources are only spent on robots, and only one robot is built per minute,
+        // so there is no use in collecting more of a resource than any robot costs.
+        let mut useful = [0; 4];
+        for cost in &self.costs {
+            for (resource, amount) in cost.iter().enumerate() {
+                useful[resource] = useful[resource].max(*amount);
+            }
+        }
+        useful[GEODE] = u32::MAX;
+        let mut best = 0;
+        self.explore(minutes, [1, 0, 0, 0], [0; 4], &useful, &mut best);
+        best
+    }
+
+    /// Chooses which robot to build next, and waits until it can be built.
+    fn explore(
+        &self,
+        minutes: u32,
+        robots: [u32; 4],
+        resources: [u32; 4],
+        useful: &[u32; 4],
+        best: &mut u32,
+    ) {
+        // Geodes if no other robot is built
+        let geodes = resources[GEODE] + robots[GEODE] * minutes;
+        *best = (*best).max(geodes);
+        // Even with a geode robot built every minute, this cannot beat the best
+        if geodes + minutes * minutes.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+        for robot in (ORE..=GEODE).rev() {
+            if robots[robot] >= useful[robot] {
+                continue;
+            }
+            let cost = self.costs[robot];
+            // Minutes until there is enough of every resource, plus a minute to build
+            let wait = (ORE..=GEODE).try_fold(0, |wait, resource| {
+                if cost[resource] <= resources[resource] {
+                    Some(wait)
+                } else if robots[resource] == 0 {
+                    None
+                } else {
+                    let missing = cost[resource] - resources[resource];
+                    Some(wait.max(missing.div_ceil(robots[resource])))
+                }
+            });
+            let elapsed = match wait {
+                Some(wait) if wait + 1 < minutes => wait + 1,
+                _ => continue,
+            };
+            let mut next_resources = resources;
+            for resource in ORE..=GEODE {
+                next_resources[resource] += robots[resource] * elapsed;
+                next_resources[resource] -= cost[resource];
+            }
+            let mut next_robots = robots;
+            next_robots[robot] += 1;
+            self.explore(
+                minutes - elapsed,
+                next_robots,
+                next_resources,
+                useful,
+                best,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_most_geodes() {
+        // Geode robots are free, so build one every minute after the first
+        let blueprint = Blueprint::new("Blueprint 1: Each ore robot costs 9 ore. Each clay robot costs 9 ore. Each obsidian robot costs 9 ore and 9 clay. Each geode robot costs 0 ore and 0 obsidian.").unwrap();
+        assert_eq!(blueprint.id, 1);
+        assert_eq!(blueprint.costs[OBSIDIAN], [9, 9, 0, 0]);
+        assert_eq!(blueprint.most_geodes(1), 0);
+        assert_eq!(blueprint.most_geodes(2), 1);
+        assert_eq!(blueprint.most_geodes(4), 3 + 2 + 1);
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    /// Blueprints may be written over several lines
+    fn parse(lines: Vec<String>) -> Result<Vec<Blueprint>> {
+        let input = lines.join(" ");
+        input
+            .split("Blueprint")
+            .skip(1)
+            .map(|blueprint| {
+                let blueprint = format!(
+                    "Blueprint {}",
+                    blueprint.split_whitespace().collect::<Vec<_>>().join(" ")
+                );
+                Blueprint::new(&blueprint)
+                    .with_context(|| format!("could not parse `{}`", blueprint))
+            })
+            .collect()
+    }
+
+    /// Part 1: Add up the quality levels of every blueprint in 24 minutes
+    fn part1(blueprints: &Vec<Blueprint>) -> Result<Answer> {
+        let quality = blueprints
+            .iter()
+            .map(|blueprint| blueprint.id * blueprint.most_geodes(24))
+            .sum::<u32>();
+        Ok(quality.into())
+    }
+
+    /// Part 2: Multiply the geodes of the first three blueprints in 32 minutes
+    fn part2(blueprints: &Vec<Blueprint>) -> Result<Answer> {
+        let product = blueprints
+            .iter()
+            .take(3)
+            .map(|blueprint| blueprint.most_geodes(32) as u64)
+            .product::<u64>();
+        Ok(product.into())
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+/// Mixes the numbers some times, moving each number in their original order
+/// forward or backward as many positions as its value.
+/// Returns the mixed numbers.
+fn mix(numbers: &[i64], times: usize) -> Vec<i64> {
+    // A lone number has nowhere to move
+    if numbers.len() <= 1 {
+        return numbers.to_vec();
+    }
+    // Original index of the number at each position
+    let mut order = (0..numbers.len()).collect::<Vec<_>>();
+    // A number that goes around the list passes every other number once
+    let laps = numbers.len() as i64 - 1;
+    for _ in 0..times {
+        for (index, number) in numbers.iter().enumerate() {
+            let from = order.iter().position(|i| *i == index).unwrap();
+            order.remove(from);
+            let to = (from as i64 + number).rem_euclid(laps);
+            order.insert(to as usize, index);
+        }
+    }
+    order.iter().map(|index| numbers[*index]).collect()
+}
+
+/// Sums the 1000th, 2000th and 3000th numbers after 0
+fn grove_coordinates(mixed: &[i64]) -> i64 {
+    let zero = mixed.iter().position(|n| *n == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| mixed[(zero + offset) % mixed.len()])
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rotates numbers so that it starts with 0, as the list is circular
+    fn from_zero(numbers: Vec<i64>) -> Vec<i64> {
+        let zero = numbers.iter().position(|n| *n == 0).unwrap();
+        [&numbers[zero..], &numbers[..zero]].concat()
+    }
+
+    #[test]
+    fn test_mix() {
+        // 1 moves past 2, and then 2 goes around the list back to where it was
+        assert_eq!(from_zero(mix(&[0, 1, 2], 1)), vec![0, 2, 1]);
+        // -1 moves back past 0, 3 goes around the list back to where it was,
+        // and 5 goes around and then moves past -1 and 0
+        assert_eq!(from_zero(mix(&[0, -1, 3, 5], 1)), vec![0, 5, 3, -1]);
+        assert_eq!(mix(&[0], 10), vec![0]);
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<i64>> {
+        let numbers = lines
+            .iter()
+            .map(|line| {
+                line.parse()
+                    .with_context(|| format!("could not parse `{}`", line))
+            })
+            .collect::<Result<Vec<i64>>>()?;
+        if numbers.iter().filter(|n| **n == 0).count() != 1 {
+            bail!("expected exactly one 0");
+        }
+        Ok(numbers)
+    }
+
+    /// Part 1: Mix once
+    fn part1(numbers: &Vec<i64>) -> Result<Answer> {
+        Ok(grove_coordinates(&mix(numbers, 1)).into())
+    }
+
+    /// Part 2: Apply the decryption key and mix ten times
+    fn part2(numbers: &Vec<i64>) -> Result<Answer> {
+        let numbers =
+            numbers.iter().map(|n| n * DECRYPTION_KEY).collect::<Vec<_>>();
+        Ok(grove_coordinates(&mix(&numbers, 10)).into())
+    }
+}
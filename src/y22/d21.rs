@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Job {
+    Number(i64),
+    /// The operation and the monkeys whose numbers it takes
+    Operation(char, String, String),
+}
+
+impl Job {
+    fn new(input: &str) -> Result<Job> {
+        if let Ok(number) = input.parse() {
+            return Ok(Job::Number(number));
+        }
+        match input.split(' ').collect::<Vec<_>>()[..] {
+            [left, op, right] if ["+", "-", "*", "/"].contains(&op) => {
+                let op = op.chars().next().unwrap();
+                Ok(Job::Operation(op, left.to_string(), right.to_string()))
+            }
+            _ => bail!("invalid job `{}`", input),
+        }
+    }
+}
+
+pub struct Monkeys {
+    jobs: HashMap<String, Job>,
+}
+
+impl Monkeys {
+    fn new(lines: &[String]) -> Result<Monkeys> {
+        let mut jobs = HashMap::new();
+        for line in lines {
+            let (name, job) = line
+                .split_once(": ")
+                .with_context(|| format!("invalid line `{}`", line))?;
+            jobs.insert(name.to_string(), Job::new(job)?);
+        }
+        for name in [ROOT, HUMAN] {
+            if !jobs.contains_key(name) {
+                bail!("there is no monkey {}", name);
+            }
+        }
+        Ok(Monkeys { jobs })
+    }
+
+    fn job(&self, name: &str) -> Result<&Job> {
+        self.jobs
+            .get(name)
+            .with_context(|| format!("there is no monkey {}", name))
+    }
+
+    /// The number that a monkey yells
+    fn yell(&self, name: &str) -> Result<i64> {
+        match self.job(name)? {
+            Job::Number(number) => Ok(*number),
+            Job::Operation(op, left, right) => {
+                let (left, right) = (self.yell(left)?, self.yell(right)?);
+                match op {
+                    '+' => Ok(left + right),
+                    '-' => Ok(left - right),
+                    '*' => Ok(left * right),
+                    _ if right == 0 => bail!("{} divides by zero", name),
+                    _ => Ok(left / right),
+                }
+            }
+        }
+    }
+
+    /// Whether the human's number is needed for the monkey's number
+    fn depends_on_human(&self, name: &str) -> bool {
+        if name == HUMAN {
+            return true;
+        }
+        match self.jobs.get(name) {
+            Some(Job::Operation(_, left, right)) => {
+                self.depends_on_human(left) || self.depends_on_human(right)
+            }
+            _ => false,
+        }
+    }
+
+    /// What the human has to yell for the monkey to yell target
+    fn solve_for_human(&self, name: &str, target: i64) -> Result<i64> {
+        if name == HUMAN {
+            return Ok(target);
+        }
+        let (op, left, right) = match self.job(name)? {
+            Job::Operation(op, left, right) => (*op, left, right),
+            Job::Number(_) => bail!("{} does not depend on {}", name, HUMAN),
+        };
+        if self.depends_on_human(left) {
+            let right = self.yell(right)?;
+            // target == left op right
+            let left_target = match op {
+                '+' => target - right,
+                '-' => target + right,
+                '*' if right != 0 && target % right == 0 => target / right,
+                '/' => target * right,
+                _ => bail!("{} cannot yell {}", name, target),
+            };
+            self.solve_for_human(left, left_target)
+        } else {
+            let left = self.yell(left)?;
+            // target == left op right
+            let right_target = match op {
+                '+' => target - left,
+                '-' => left - target,
+                '*' if left != 0 && target % left == 0 => target / left,
+                '/' if target != 0 && left % target == 0 => left / target,
+                _ => bail!("{} cannot yell {}", name, target),
+            };
+            self.solve_for_human(right, right_target)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_for_human() {
+        let lines =
+            ["root: abcd - efgh", "abcd: 100", "efgh: abcd / humn", "humn: 4"]
+                .map(|s| s.to_string());
+        let monkeys = Monkeys::new(&lines).unwrap();
+        assert_eq!(monkeys.yell(ROOT).unwrap(), 75);
+        assert!(!monkeys.depends_on_human("abcd"));
+        assert!(monkeys.depends_on_human("efgh"));
+        assert_eq!(monkeys.solve_for_human("efgh", 20).unwrap(), 5);
+        assert_eq!(monkeys.solve_for_human(ROOT, 90).unwrap(), 10);
+        assert!(monkeys.solve_for_human(ROOT, 70).is_err());
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Monkeys;
+
+    fn parse(lines: Vec<String>) -> Result<Monkeys> {
+        Monkeys::new(&lines)
+    }
+
+    /// Part 1: Find what the root monkey yells
+    fn part1(monkeys: &Monkeys) -> Result<Answer> {
+        Ok(monkeys.yell(ROOT)?.into())
+    }
+
+    /// Part 2: The root monkey checks if two numbers are equal,
+    /// and the human has to yell something that makes them equal
+    fn part2(monkeys: &Monkeys) -> Result<Answer> {
+        let (left, right) = match monkeys.job(ROOT)? {
+            Job::Operation(_, left, right) => (left, right),
+            Job::Number(_) => bail!("{} does not compare numbers", ROOT),
+        };
+        let number = match (
+            monkeys.depends_on_human(left),
+            monkeys.depends_on_human(right),
+        ) {
+            (true, false) => {
+                monkeys.solve_for_human(left, monkeys.yell(right)?)?
+            }
+            (false, true) => {
+                monkeys.solve_for_human(right, monkeys.yell(left)?)?
+            }
+            _ => bail!("exactly one side of {} must depend on {}", ROOT, HUMAN),
+        };
+        Ok(number.into())
+    }
+}
@@ -0,0 +1,354 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::geom::{Direction, Point, Point3};
+use crate::util::solution::Solution;
+use crate::util::vectors::group;
+
+const OPEN: u8 = b'.';
+const WALL: u8 = b'#';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+fn parse_path(input: &str) -> Result<Vec<Step>> {
+    let mut result = vec![];
+    let mut digits = String::new();
+    for c in input.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        if !digits.is_empty() {
+            result.push(Step::Forward(digits.parse()?));
+            digits.clear();
+        }
+        match c {
+            'L' => result.push(Step::Left),
+            'R' => result.push(Step::Right),
+            _ => bail!("invalid step `{}`", c),
+        }
+    }
+    if !digits.is_empty() {
+        result.push(Step::Forward(digits.parse()?));
+    }
+    Ok(result)
+}
+
+/// Value of a facing in the final password
+fn facing_value(direction: Direction) -> i64 {
+    match direction {
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+        Direction::North => 3,
+    }
+}
+
+fn dot(a: Point3, b: Point3) -> i64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// A face of the cube that the map folds into.
+/// Its orientation is given by three unit vectors in 3D.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    /// Topleftmost tile of the face on the map
+    origin: Point,
+    /// Points out of the cube
+    normal: Point3,
+    /// Where East on the map points to
+    right: Point3,
+    /// Where South on the map points to
+    down: Point3,
+}
+
+impl Face {
+    /// Where a direction on the map points to
+    fn towards(&self, direction: Direction) -> Point3 {
+        match direction {
+            Direction::East => self.right,
+            Direction::West => -self.right,
+            Direction::South => self.down,
+            Direction::North => -self.down,
+        }
+    }
+
+    /// Face on the other side of the edge towards direction.
+    /// Folds the map along the edge, which rotates the face around it.
+    fn fold(&self, direction: Direction, size: i64) -> Face {
+        let origin = self.origin + direction.delta() * size;
+        let (normal, right, down) = (self.normal, self.right, self.down);
+        match direction {
+            Direction::East => {
+                Face { origin, normal: right, right: -normal, down }
+            }
+            Direction::West => {
+                Face { origin, normal: -right, right: normal, down }
+            }
+            Direction::South => {
+                Face { origin, normal: down, right, down: -normal }
+            }
+            Direction::North => {
+                Face { origin, normal: -down, right, down: normal }
+            }
+        }
+    }
+}
+
+pub struct Board {
+    /// Rows of tiles, which may have different lengths
+    tiles: Vec<Vec<u8>>,
+    path: Vec<Step>,
+    /// Length of the edges of the cube
+    size: i64,
+    /// The faces of the cube, by the position of their topleftmost tiles
+    faces: HashMap<Point, Face>,
+}
+
+impl Board {
+    fn new(lines: Vec<String>) -> Result<Board> {
+        let groups = group(lines);
+        let [map, path] = &groups[..] else {
+            bail!("expected map and path, got {} groups", groups.len());
+        };
+        let path = match &path[..] {
+            [path] => parse_path(path).context("could not parse path")?,
+            _ => bail!("expected path to be 1 line, got {}", path.len()),
+        };
+        let tiles = map
+            .iter()
+            .map(|line| line.bytes().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        if let Some(c) =
+            tiles.iter().flatten().find(|c| ![b' ', OPEN, WALL].contains(c))
+        {
+            bail!("invalid tile `{}`", *c as char);
+        }
+        let mut board = Board { tiles, path, size: 0, faces: HashMap::new() };
+        board.fold()?;
+        Ok(board)
+    }
+
+    fn tile(&self, pos: Point) -> Option<u8> {
+        let (x, y) =
+            (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        match self.tiles.get(y)?.get(x)? {
+            b' ' => None,
+            tile => Some(*tile),
+        }
+    }
+
+    /// Folds the map into a cube, finding where each face goes
+    fn fold(&mut self) -> Result<()> {
+        let count = self.tiles.iter().flatten().filter(|c| **c != b' ').count();
+        let size = ((count / 6) as f64).sqrt() as i64;
+        if count == 0 || (size * size * 6) as usize != count {
+            bail!("{} tiles cannot make up a cube", count);
+        }
+        let start = self.start()?;
+        let first = Face {
+            origin: Point::new(start.x - start.x % size, 0),
+            normal: Point3::new(0, 0, -1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        };
+        let mut faces = HashMap::from([(first.origin, first)]);
+        let mut queue = VecDeque::from([first]);
+        while let Some(face) = queue.pop_front() {
+            for direction in Direction::ALL {
+                let next = face.fold(direction, size);
+                if self.tile(next.origin).is_some()
+                    && !faces.contains_key(&next.origin)
+                {
+                    faces.insert(next.origin, next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        let mut normals =
+            faces.values().map(|face| face.normal).collect::<Vec<_>>();
+        normals.sort();
+        normals.dedup();
+        if faces.len() != 6 || normals.len() != 6 {
+            bail!("map does not fold into a cube");
+        }
+        self.size = size;
+        self.faces = faces;
+        Ok(())
+    }
+
+    /// The leftmost open tile of the top row
+    fn start(&self) -> Result<Point> {
+        let x = self.tiles[0]
+            .iter()
+            .position(|c| *c == OPEN)
+            .context("there is no open tile in the top row")?;
+        Ok(Point::new(x as i64, 0))
+    }
+
+    /// Where one ends up after walking off the map from pos towards direction,
+    /// if the map wraps around
+    fn wrap_flat(
+        &self,
+        pos: Point,
+        direction: Direction,
+    ) -> (Point, Direction) {
+        let back = direction.rev().delta();
+        let mut pos = pos;
+        while self.tile(pos + back).is_some() {
+            pos += back;
+        }
+        (pos, direction)
+    }
+
+    /// Where one ends up after walking off the map from pos towards direction,
+    /// if the map is folded into a cube.
+    /// Tiles are placed in 3D, on a cube that spans -size to size along each axis,
+    /// such that their centers are two apart.
+    fn wrap_cube(
+        &self,
+        pos: Point,
+        direction: Direction,
+    ) -> (Point, Direction) {
+        let size = self.size;
+        let origin = Point::new(pos.x - pos.x % size, pos.y - pos.y % size);
+        let face = self.faces[&origin];
+        let local = pos - origin;
+        let center = face.normal * size
+            + face.right * (2 * local.x + 1 - size)
+            + face.down * (2 * local.y + 1 - size);
+
+        // Over the edge and onto the next face, which faces towards direction
+        let towards = face.towards(direction);
+        let next = self.faces.values().find(|f| f.normal == towards).unwrap();
+        let center = center + towards - face.normal;
+        let local = Point::new(
+            (dot(center, next.right) + size - 1) / 2,
+            (dot(center, next.down) + size - 1) / 2,
+        );
+        // Walking down into the cube on the next face
+        let direction = Direction::ALL
+            .into_iter()
+            .find(|d| next.towards(*d) == -face.normal)
+            .unwrap();
+        (next.origin + local, direction)
+    }
+
+    /// Follows the path, and returns the final password
+    fn walk(&self, on_cube: bool) -> Result<i64> {
+        let mut pos = self.start()?;
+        let mut direction = Direction::East;
+        for step in &self.path {
+            let steps = match step {
+                Step::Left => {
+                    direction = direction.turn_left();
+                    continue;
+                }
+                Step::Right => {
+                    direction = direction.turn_right();
+                    continue;
+                }
+                Step::Forward(steps) => *steps,
+            };
+            for _ in 0..steps {
+                let (next, next_direction) =
+                    match self.tile(pos + direction.delta()) {
+                        Some(_) => (pos + direction.delta(), direction),
+                        None if on_cube => self.wrap_cube(pos, direction),
+                        None => self.wrap_flat(pos, direction),
+                    };
+                if self.tile(next) == Some(WALL) {
+                    break;
+                }
+                (pos, direction) = (next, next_direction);
+            }
+        }
+        Ok(1000 * (pos.y + 1) + 4 * (pos.x + 1) + facing_value(direction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(map: &[&str], path: &str) -> Board {
+        let mut lines = map.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        lines.extend(["".to_string(), path.to_string()]);
+        Board::new(lines).unwrap()
+    }
+
+    #[test]
+    fn test_wrap() {
+        // Unfolded cube, where each face is a single tile
+        //  A
+        // BCDE
+        //  F
+        let board = board(&[" .", "....", " ."], "1");
+        assert_eq!(board.size, 1);
+        // Off the left of B is E, both flat and on the cube
+        let b = Point::new(0, 1);
+        assert_eq!(
+            board.wrap_flat(b, Direction::West),
+            (Point::new(3, 1), Direction::West)
+        );
+        assert_eq!(
+            board.wrap_cube(b, Direction::West),
+            (Point::new(3, 1), Direction::West)
+        );
+        // Off the top of B is A, entering from its left
+        assert_eq!(
+            board.wrap_cube(b, Direction::North),
+            (Point::new(1, 0), Direction::East)
+        );
+        // Off the bottom of E is F, entering from its bottom
+        assert_eq!(
+            board.wrap_cube(Point::new(3, 1), Direction::South),
+            (Point::new(1, 2), Direction::North)
+        );
+        // Off the top of A is E, entering from its top
+        assert_eq!(
+            board.wrap_cube(Point::new(1, 0), Direction::North),
+            (Point::new(3, 1), Direction::South)
+        );
+    }
+
+    #[test]
+    fn test_walk() {
+        let board = board(
+            &["  ..", "  ..", "....#...", "........", "  ..", "  .."],
+            "3R2L1",
+        );
+        assert_eq!(board.size, 2);
+        // Wraps back to the left, and then is stopped by the wall
+        assert_eq!(board.walk(false).unwrap(), 1000 * 3 + 4 * 4);
+        // Walks down the right face, and then back onto the front face
+        assert_eq!(board.walk(true).unwrap(), 1000 * 5 + 4 * 4 + 1);
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Board;
+
+    fn parse(lines: Vec<String>) -> Result<Board> {
+        Board::new(lines)
+    }
+
+    /// Part 1: The map wraps around
+    fn part1(board: &Board) -> Result<Answer> {
+        Ok(board.walk(false)?.into())
+    }
+
+    /// Part 2: The map is folded into a cube
+    fn part2(board: &Board) -> Result<Answer> {
+        Ok(board.walk(true)?.into())
+    }
+}
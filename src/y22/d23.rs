@@ -0,0 +1,132 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+
+use crate::util::answer::Answer;
+use crate::util::geom::{Direction, Direction8, Point};
+use crate::util::grid::Grid;
+use crate::util::solution::Solution;
+
+/// Directions the elves consider, in order for the first round
+const CONSIDERED: [Direction; 4] =
+    [Direction::North, Direction::South, Direction::West, Direction::East];
+
+/// Positions an elf checks before moving towards direction:
+/// the one in that direction, and the two diagonals next to it
+fn checked(elf: Point, direction: Direction) -> [Point; 3] {
+    let direction = Direction8::from(direction);
+    [direction.turn_left(), direction, direction.turn_right()]
+        .map(|d| elf + d.delta())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grove {
+    elves: HashSet<Point>,
+    /// How many rounds have passed
+    rounds: usize,
+}
+
+impl Grove {
+    /// Every elf proposes a move, and moves there if no other elf proposed it.
+    /// Returns whether any elf moved.
+    fn round(&mut self) -> bool {
+        // Elves that proposed each position
+        let mut proposals: HashMap<Point, Vec<Point>> = HashMap::new();
+        for &elf in &self.elves {
+            if elf.neighbors8().iter().all(|p| !self.elves.contains(p)) {
+                continue;
+            }
+            let proposal =
+                (0..4).map(|ii| CONSIDERED[(self.rounds + ii) % 4]).find(|d| {
+                    checked(elf, *d).iter().all(|p| !self.elves.contains(p))
+                });
+            if let Some(direction) = proposal {
+                proposals.entry(elf + direction.delta()).or_default().push(elf);
+            }
+        }
+        let mut moved = false;
+        for (to, from) in proposals {
+            if let [from] = from[..] {
+                self.elves.remove(&from);
+                self.elves.insert(to);
+                moved = true;
+            }
+        }
+        self.rounds += 1;
+        moved
+    }
+
+    /// Counts the empty tiles within the smallest rectangle that has every elf
+    fn empty_ground(&self) -> usize {
+        let xs = self.elves.iter().map(|elf| elf.x);
+        let ys = self.elves.iter().map(|elf| elf.y);
+        let width = xs.clone().max().unwrap() - xs.min().unwrap() + 1;
+        let height = ys.clone().max().unwrap() - ys.min().unwrap() + 1;
+        (width * height) as usize - self.elves.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round() {
+        let grove = |lines: &[&str]| {
+            let lines = lines.iter().map(|s| s.to_string()).collect();
+            Day23::parse(lines).unwrap()
+        };
+        let mut small =
+            grove(&[".....", "..##.", "..#..", ".....", "..##.", "....."]);
+        let expected = [
+            grove(&["..##.", ".....", "..#..", "...#.", "..#..", "....."]),
+            grove(&[".....", "..##.", ".#...", "....#", ".....", "..#.."]),
+            grove(&["..#..", "....#", "#....", "....#", ".....", "..#.."]),
+        ];
+        for expected in &expected {
+            assert!(small.round());
+            assert_eq!(small.elves, expected.elves);
+        }
+        assert!(!small.round());
+        assert_eq!(small.empty_ground(), 5 * 6 - 5);
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grove;
+
+    fn parse(lines: Vec<String>) -> Result<Grove> {
+        let tiles = Grid::parse_with(&lines, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let elves = tiles
+            .iter()
+            .filter(|(_, elf)| **elf)
+            .map(|(pos, _)| Point::from_grid_pos(pos))
+            .collect::<HashSet<_>>();
+        if elves.is_empty() {
+            bail!("there are no elves");
+        }
+        Ok(Grove { elves, rounds: 0 })
+    }
+
+    /// Part 1: Spread out for ten rounds
+    fn part1(grove: &Grove) -> Result<Answer> {
+        let mut grove = grove.clone();
+        for _ in 0..10 {
+            grove.round();
+        }
+        Ok(grove.empty_ground().into())
+    }
+
+    /// Part 2: Spread out until no elf moves
+    fn part2(grove: &Grove) -> Result<Answer> {
+        let mut grove = grove.clone();
+        while grove.round() {}
+        Ok(grove.rounds.into())
+    }
+}
@@ -0,0 +1,164 @@
+use anyhow::{bail, Context, Result};
+use num::integer::lcm;
+
+use crate::util::answer::Answer;
+use crate::util::geom::{Direction, Point};
+use crate::util::grid::Grid;
+use crate::util::search;
+use crate::util::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Clear,
+    Blizzard(Direction),
+}
+
+pub struct Valley {
+    /// Tiles at minute 0
+    tiles: Grid<Tile>,
+    /// The only clear tiles on the top and bottom walls
+    start: Point,
+    end: Point,
+}
+
+impl Valley {
+    fn new(lines: &[String]) -> Result<Valley> {
+        let tiles = Grid::parse_with(lines, |c| match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Clear),
+            '^' => Some(Tile::Blizzard(Direction::North)),
+            '>' => Some(Tile::Blizzard(Direction::East)),
+            'v' => Some(Tile::Blizzard(Direction::South)),
+            '<' => Some(Tile::Blizzard(Direction::West)),
+            _ => None,
+        })?;
+        let (width, height) = (tiles.width(), tiles.height());
+        if width < 3 || height < 3 {
+            bail!("valley is too small");
+        }
+        let opening = |y: usize| {
+            let openings = (0..width)
+                .filter(|x| tiles[(*x, y)] == Tile::Clear)
+                .collect::<Vec<_>>();
+            match openings[..] {
+                [x] => Ok(Point::from_grid_pos((x, y))),
+                _ => bail!("expected 1 opening in row {}", y),
+            }
+        };
+        let (start, end) = (opening(0)?, opening(height - 1)?);
+        for ((x, y), tile) in tiles.iter() {
+            let Tile::Blizzard(direction) = tile else {
+                continue;
+            };
+            let on_wall = x == 0 || y == 0 || x == width - 1 || y == height - 1;
+            // Blizzards wrap around the walls, but would go out the openings
+            let vertical = [Direction::North, Direction::South];
+            let below_opening = [start.x, end.x].contains(&(x as i64))
+                && vertical.contains(direction);
+            if on_wall || below_opening {
+                bail!("blizzard at ({},{}) would leave the valley", x, y);
+            }
+        }
+        Ok(Valley { tiles, start, end })
+    }
+
+    /// Whether pos is clear of walls and blizzards at some minute
+    fn is_clear(&self, pos: Point, minute: usize) -> bool {
+        let (width, height) =
+            (self.tiles.width() as i64, self.tiles.height() as i64);
+        let Some((x, y)) = pos.to_grid_pos((width as usize, height as usize))
+        else {
+            return false;
+        };
+        match self.tiles[(x, y)] {
+            Tile::Wall => return false,
+            _ if pos == self.start || pos == self.end => return true,
+            _ => {}
+        }
+        // Blizzards move within the walls, and wrap around them.
+        // Looks for the blizzards that would be at pos at this minute.
+        let (inner_width, inner_height) = (width - 2, height - 2);
+        let minute = minute as i64;
+        Direction::ALL.into_iter().all(|direction| {
+            let delta = direction.delta() * minute;
+            let x = (pos.x - 1 - delta.x).rem_euclid(inner_width) + 1;
+            let y = (pos.y - 1 - delta.y).rem_euclid(inner_height) + 1;
+            self.tiles[(x as usize, y as usize)] != Tile::Blizzard(direction)
+        })
+    }
+
+    /// Fewest minutes to go from one place to another, starting at some minute
+    fn cross(&self, from: Point, to: Point, minute: usize) -> Option<usize> {
+        // Blizzards are back where they started after this many minutes,
+        // so states are the same modulo it
+        let period = lcm(self.tiles.width() - 2, self.tiles.height() - 2);
+        let (minutes, _) = search::bfs(
+            (from, minute % period),
+            |&(pos, minute)| {
+                let waits = std::iter::once(pos);
+                let moves =
+                    Direction::ALL.into_iter().map(move |d| pos + d.delta());
+                waits
+                    .chain(moves)
+                    .filter(|next| self.is_clear(*next, minute + 1))
+                    .map(|next| (next, (minute + 1) % period))
+                    .collect::<Vec<_>>()
+            },
+            |&(pos, _)| pos == to,
+        )?;
+        Some(minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_clear() {
+        let lines = [
+            "#.#####", "#.....#", "#>....#", "#.....#", "#.....#", "#.....#",
+            "#####.#",
+        ]
+        .map(|s| s.to_string());
+        let valley = Valley::new(&lines).unwrap();
+        assert!(valley.is_clear(valley.start, 0));
+        assert!(!valley.is_clear(Point::new(1, 2), 0));
+        assert!(!valley.is_clear(Point::new(4, 2), 3));
+        // Wraps around to the left
+        assert!(!valley.is_clear(Point::new(1, 2), 5));
+        assert!(valley.is_clear(Point::new(1, 2), 4));
+        assert!(!valley.is_clear(Point::new(0, 2), 4));
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+
+    fn parse(lines: Vec<String>) -> Result<Valley> {
+        Valley::new(&lines)
+    }
+
+    /// Part 1: Cross the valley
+    fn part1(valley: &Valley) -> Result<Answer> {
+        let minutes = valley
+            .cross(valley.start, valley.end, 0)
+            .context("cannot cross the valley")?;
+        Ok(minutes.into())
+    }
+
+    /// Part 2: Cross the valley, go back for the snacks, and cross again
+    fn part2(valley: &Valley) -> Result<Answer> {
+        let (start, end) = (valley.start, valley.end);
+        let mut minute = 0;
+        for (from, to) in [(start, end), (end, start), (start, end)] {
+            minute += valley
+                .cross(from, to, minute)
+                .context("cannot cross the valley")?;
+        }
+        Ok(minute.into())
+    }
+}
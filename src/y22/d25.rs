@@ -0,0 +1,88 @@
+use anyhow::{bail, Result};
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+/// Parses a SNAFU number, whose digits are 2, 1, 0, - (-1) and = (-2)
+fn from_snafu(input: &str) -> Result<i64> {
+    if input.is_empty() {
+        bail!("empty SNAFU number");
+    }
+    let mut result: i64 = 0;
+    for c in input.chars() {
+        let digit = match c {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+            _ => bail!("invalid SNAFU digit `{}` in `{}`", c, input),
+        };
+        result = result * 5 + digit;
+    }
+    Ok(result)
+}
+
+/// Writes a number in SNAFU
+fn to_snafu(number: i64) -> String {
+    if number == 0 {
+        return "0".to_string();
+    }
+    let mut digits = vec![];
+    let mut number = number;
+    while number != 0 {
+        // Digits 3 and 4 become = and -, carrying one over to the next digit
+        let (digit, carry) = match number.rem_euclid(5) {
+            0 => ('0', 0),
+            1 => ('1', 1),
+            2 => ('2', 2),
+            3 => ('=', -2),
+            _ => ('-', -1),
+        };
+        digits.push(digit);
+        number = (number - carry) / 5;
+    }
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snafu() {
+        for (number, snafu) in [
+            (1, "1"),
+            (3, "1="),
+            (8, "2="),
+            (10, "20"),
+            (2022, "1=11-2"),
+            (314159265, "1121-1110-1=0"),
+            (-3, "-2"),
+        ] {
+            assert_eq!(from_snafu(snafu).unwrap(), number);
+            assert_eq!(to_snafu(number), snafu);
+        }
+        assert!(from_snafu("123").is_err());
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<i64>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<i64>> {
+        lines.iter().map(|line| from_snafu(line)).collect()
+    }
+
+    /// Part 1: Sum the fuel requirements, in SNAFU
+    fn part1(numbers: &Vec<i64>) -> Result<Answer> {
+        Ok(to_snafu(numbers.iter().sum()).into())
+    }
+
+    /// Part 2: There is no part 2
+    fn part2(_: &Vec<i64>) -> Result<Answer> {
+        Ok("Merry Christmas!".into())
+    }
+}
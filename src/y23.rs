@@ -1,4 +1,4 @@
-use anyhow::Result;
+use crate::util::Solver;
 
 mod d01;
 mod d02;
@@ -16,31 +16,41 @@ mod d13;
 mod d14;
 mod d15;
 mod d16;
+mod d17;
+mod d18;
 mod d19;
+mod d20;
 mod d21;
+mod d22;
+mod d23;
+mod d24;
+mod d25;
 
-pub fn solver(
-    day: u8,
-) -> fn(Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    match day {
-        1 => d01::solve,
-        2 => d02::solve,
-        3 => d03::solve,
-        4 => d04::solve,
-        5 => d05::solve,
-        6 => d06::solve,
-        7 => d07::solve,
-        8 => d08::solve,
-        9 => d09::solve,
-        10 => d10::solve,
-        11 => d11::solve,
-        12 => d12::solve,
-        13 => d13::solve,
-        14 => d14::solve,
-        15 => d15::solve,
-        16 => d16::solve,
-        19 => d19::solve,
-        21 => d21::solve,
-        _ => todo!("day {}", day),
-    }
-}
+/// Solvers for every implemented day, in increasing order of day.
+pub const SOLVERS: &[(u8, Solver)] = &[
+    (1, Solver::Whole(d01::solve)),
+    (2, Solver::Whole(d02::solve)),
+    (3, Solver::Whole(d03::solve)),
+    (4, Solver::Whole(d04::solve)),
+    (5, Solver::Whole(d05::solve)),
+    (6, Solver::Whole(d06::solve)),
+    (7, Solver::Whole(d07::solve)),
+    (8, Solver::staged::<d08::Day08>()),
+    (9, Solver::Whole(d09::solve)),
+    (10, Solver::Whole(d10::solve)),
+    (11, Solver::Whole(d11::solve)),
+    (12, Solver::Whole(d12::solve)),
+    (13, Solver::Whole(d13::solve)),
+    (14, Solver::Whole(d14::solve)),
+    (15, Solver::Whole(d15::solve)),
+    (16, Solver::Whole(d16::solve)),
+    (17, Solver::staged::<d17::Day17>()),
+    (18, Solver::staged::<d18::Day18>()),
+    (19, Solver::Whole(d19::solve)),
+    (20, Solver::staged::<d20::Day20>()),
+    (21, Solver::Whole(d21::solve)),
+    (22, Solver::staged::<d22::Day22>()),
+    (23, Solver::staged::<d23::Day23>()),
+    (24, Solver::staged::<d24::Day24>()),
+    (25, Solver::staged::<d25::Day25>()),
+];
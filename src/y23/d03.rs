@@ -3,7 +3,8 @@ use std::vec;
 use anyhow::{Context, Result};
 use itertools::Itertools;
 
-use crate::util::vectors::Grid;
+use crate::trace;
+use crate::util::grid::Grid;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum SchematicChar {
@@ -26,39 +27,23 @@ impl SchematicChar {
 }
 
 struct Schematic {
-    width: usize,
-    height: usize,
-    chars: Vec<Vec<SchematicChar>>,
+    chars: Grid<SchematicChar>,
 }
 
 impl Schematic {
-    fn new(grid: &Grid) -> Schematic {
-        Schematic {
-            width: grid.width,
-            height: grid.height,
-            chars: grid
-                .chars
-                .iter()
-                .map(|row| row.iter().map(|c| SchematicChar::new(*c)).collect())
-                .collect(),
-        }
+    fn new(lines: &[String]) -> Result<Schematic> {
+        let chars = Grid::parse_with(lines, |c| Some(SchematicChar::new(c)))?;
+        Ok(Schematic { chars })
     }
 
     /// Lists all symbols, each having the format (SchematicChar::Symbol,(pos_x, pos_y))
     fn list_symbols(&self) -> Vec<(SchematicChar, (usize, usize))> {
         self.chars
             .iter()
-            .enumerate()
-            .map(|(pos_y, list)| {
-                list.into_iter()
-                    .enumerate()
-                    .filter_map(|(pos_x, c)| match c {
-                        SchematicChar::Symbol(_) => Some((*c, (pos_x, pos_y))),
-                        _ => None,
-                    })
-                    .collect::<Vec<_>>()
+            .filter_map(|(pos, c)| match c {
+                SchematicChar::Symbol(_) => Some((*c, pos)),
+                _ => None,
             })
-            .flatten()
             .collect::<Vec<_>>()
     }
 
@@ -66,7 +51,7 @@ impl Schematic {
     /// such that the part number is located between grid[pos_y][pos_x_1] and grid[pos_y][pos_x_2-1]
     fn list_part_numbers(&self) -> Vec<(u32, (usize, usize), usize)> {
         self.chars
-            .iter()
+            .rows()
             .enumerate()
             .map(|(pos_y, row)| {
                 Schematic::list_part_numbers_per_row(row)
@@ -83,7 +68,7 @@ impl Schematic {
     /// Lists part numbers in a vector of chars, together with their starting and ending positions.
     /// That is, an entry (num, (pos_x1, pos_x2)) will have a (pos_x2-pos_x1+1)-digit number `num` from `row[pos_x1]` to `pos[pos_x2+1]`.
     fn list_part_numbers_per_row(
-        row: &Vec<SchematicChar>,
+        row: &[SchematicChar],
     ) -> Vec<(u32, (usize, usize))> {
         let mut result = vec![];
         let mut starting_index = None;
@@ -118,34 +103,10 @@ impl Schematic {
 
     /// Returns whether there's a symbol *surrounding* the position (pos_x, pos_y).
     fn has_symbol_surrounding(&self, pos_x: usize, pos_y: usize) -> bool {
-        let offsets = [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ];
-        offsets
-            .into_iter()
-            .map(|(o_1, o_2)| (pos_x as i32 + o_1, pos_y as i32 + o_2))
-            .filter_map(|(pos_x, pos_y)| {
-                if pos_x >= 0
-                    && pos_y >= 0
-                    && pos_x < self.width as i32
-                    && pos_y < self.height as i32
-                {
-                    Some((pos_x as usize, pos_y as usize))
-                } else {
-                    None
-                }
-            })
-            .any(|(pos_x, pos_y)| match self.chars[pos_y][pos_x] {
-                SchematicChar::Symbol(_) => true,
-                _ => false,
-            })
+        self.chars.neighbors8((pos_x, pos_y)).any(|pos| match self.chars[pos] {
+            SchematicChar::Symbol(_) => true,
+            _ => false,
+        })
     }
 
     fn find_surrounding_number_pair(
@@ -153,34 +114,11 @@ impl Schematic {
         pos_x: usize,
         pos_y: usize,
     ) -> Option<(u32, u32)> {
-        let offsets = [
-            (-1, -1),
-            (0, -1),
-            (1, -1),
-            (-1, 0),
-            (1, 0),
-            (-1, 1),
-            (0, 1),
-            (1, 1),
-        ];
-        let positions = offsets
-            .into_iter()
-            .map(|(o_1, o_2)| (pos_x as i32 + o_1, pos_y as i32 + o_2))
-            .filter_map(|(pos_x, pos_y)| {
-                if pos_x >= 0
-                    && pos_y >= 0
-                    && pos_x < self.width as i32
-                    && pos_y < self.height as i32
-                {
-                    Some((pos_x as usize, pos_y as usize))
-                } else {
-                    None
-                }
-            })
-            .filter_map(|(pos_x, pos_y)| match self.chars[pos_y][pos_x] {
-                SchematicChar::Digit(_) => Some((pos_x, pos_y)),
-                _ => None,
-            });
+        // Neighbors are listed row by row, which group_by relies on
+        let positions = self
+            .chars
+            .neighbors8((pos_x, pos_y))
+            .filter(|pos| matches!(self.chars[*pos], SchematicChar::Digit(_)));
         let positions_by_row = positions
             .group_by(|(_pos_x, pos_y)| *pos_y)
             .into_iter()
@@ -235,11 +173,9 @@ impl Schematic {
     /// Returns the part number at the given position, or 0 if it doesn't exist
     fn get_number(&self, (pos_x, pos_y): (usize, usize)) -> u32 {
         let get_digit = |pos_x: usize| {
-            self.chars.get(pos_y).and_then(|row| {
-                row.get(pos_x).and_then(|c| match c {
-                    SchematicChar::Digit(d) => Some(*d),
-                    _ => None,
-                })
+            self.chars.get((pos_x, pos_y)).and_then(|c| match c {
+                SchematicChar::Digit(d) => Some(*d),
+                _ => None,
             })
         };
         let current_digit = get_digit(pos_x);
@@ -277,18 +213,16 @@ mod tests {
 
     #[test]
     fn test_part_numbers_per_row() {
-        let schematic = Schematic::new(
-            &Grid::new(&vec![
-                //123456789
-                "467..114..".to_string(),
-                "...*......".to_string(),
-                "..35..633.".to_string(),
-            ])
-            .unwrap(),
-        );
+        let schematic = Schematic::new(&vec![
+            //123456789
+            "467..114..".to_string(),
+            "...*......".to_string(),
+            "..35..633.".to_string(),
+        ])
+        .unwrap();
         let part_numbers = schematic
             .chars
-            .iter()
+            .rows()
             .map(Schematic::list_part_numbers_per_row)
             .collect::<Vec<_>>();
         assert_eq!(
@@ -303,21 +237,19 @@ mod tests {
 
     #[test]
     fn test_find_surrounding_number_pair() {
-        let schematic = Schematic::new(
-            &Grid::new(&vec![
-                "467..114..".to_string(),
-                "...*......".to_string(),
-                "..35..633.".to_string(),
-                "......#...".to_string(),
-                "617*......".to_string(),
-                ".....+.58.".to_string(),
-                "..592.....".to_string(),
-                "......755.".to_string(),
-                "...$.*....".to_string(),
-                ".664.598..".to_string(),
-            ])
-            .unwrap(),
-        );
+        let schematic = Schematic::new(&vec![
+            "467..114..".to_string(),
+            "...*......".to_string(),
+            "..35..633.".to_string(),
+            "......#...".to_string(),
+            "617*......".to_string(),
+            ".....+.58.".to_string(),
+            "..592.....".to_string(),
+            "......755.".to_string(),
+            "...$.*....".to_string(),
+            ".664.598..".to_string(),
+        ])
+        .unwrap();
         assert_eq!(schematic.get_number((0, 0)), 467);
 
         let inputs = vec![(3, 1), (5, 8)];
@@ -376,7 +308,7 @@ fn solve_part_2(schematic: &Schematic) -> Result<String> {
         })
         .collect::<Vec<_>>();
     for (pos, pair) in &all_gear_ratios {
-        println!("{:?} has pair {:?}", pos, pair);
+        trace!(Debug, "{:?} has pair {:?}", pos, pair);
     }
     let all_gear_ratios = all_gear_ratios.into_iter().map(|(_, pair)| pair);
     let ans2 = all_gear_ratios.map(|(num_1, num_2)| num_1 * num_2).sum::<u32>();
@@ -384,8 +316,8 @@ fn solve_part_2(schematic: &Schematic) -> Result<String> {
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let grid = Grid::new(&lines).context("cannot create grid")?;
-    let schematic = Schematic::new(&grid);
+    let schematic =
+        Schematic::new(&lines).context("cannot create schematic")?;
 
     Ok((solve_part_1(&schematic), solve_part_2(&schematic)))
 }
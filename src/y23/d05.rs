@@ -1,75 +1,13 @@
-use std::{collections::VecDeque, vec};
-
 use anyhow::{bail, Context, Result};
 
+use crate::util::interval::{Interval, IntervalSet};
 use crate::util::vectors::group;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-struct Range {
-    start: u64,
-    length: u64,
-}
-
-/// Combine range_1 and range_2 if they intersect.
-/// None if they don't.
-fn combine(range_1: Range, range_2: Range) -> Option<Range> {
-    // Make sure range_1 is at least before range_2
-    if range_1.start > range_2.start {
-        return combine(range_2, range_1);
-    }
-
-    // Check for disjointness
-    if range_1.start + range_1.length <= range_2.start {
-        return None;
-    }
-
-    // Finally, check if range_2 is completely inside range_1
-    if range_2.start + range_2.length <= range_1.start + range_1.length {
-        Some(range_1)
-    } else {
-        Some(Range {
-            start: range_1.start,
-            length: range_2.start - range_1.start + range_2.length,
-        })
-    }
-}
-
-fn merge_ranges(ranges: Vec<Range>) -> Vec<Range> {
-    if ranges.len() <= 1 {
-        return ranges;
-    }
-
-    let mut ranges = ranges;
-    ranges.sort_by_key(|range| range.start);
-    let mut ranges = VecDeque::from(ranges);
-    let mut result = vec![];
-    let mut current = ranges.pop_front().unwrap();
-
-    while ranges.len() > 0 {
-        let front = ranges.pop_front().unwrap();
-        if let Some(combined) = combine(current, front) {
-            current = combined;
-        } else {
-            result.push(current);
-            current = front;
-        }
-    }
-    result.push(current);
-    result
-}
-
 #[derive(Debug, PartialEq, Eq)]
 struct MapRow {
-    destination_range_start: u64,
-    source_range_start: u64,
-    range_length: u64,
-}
-
-/// MapRowMapping represents a Range being either successfully mapped via MapRow, or unmapped.
-#[derive(Debug, PartialEq, Eq)]
-enum MapRowMapping {
-    Mapped(Range),
-    Unmapped(Range),
+    destination_range_start: i64,
+    source_range_start: i64,
+    range_length: i64,
 }
 
 impl MapRow {
@@ -77,9 +15,9 @@ impl MapRow {
     fn new(input: &str) -> Result<MapRow> {
         let numbers = input
             .split(" ")
-            .map(|v| v.parse::<u64>().ok())
-            .collect::<Option<Vec<u64>>>()
-            .context("could not parse numbers to u64")?;
+            .map(|v| v.parse::<i64>().ok())
+            .collect::<Option<Vec<i64>>>()
+            .context("could not parse numbers to i64")?;
         if numbers.len() != 3 {
             bail!(
                 "there should be three numbers, got {} instead",
@@ -94,10 +32,8 @@ impl MapRow {
     }
 
     /// Returns the destination given a source. Returns None if outside of range
-    fn source_to_dest(&self, source: u64) -> Option<u64> {
-        if source >= self.source_range_start
-            && source < self.source_range_start + self.range_length
-        {
+    fn source_to_dest(&self, source: i64) -> Option<i64> {
+        if self.source().contains(source) {
             Some(
                 self.destination_range_start + source - self.source_range_start,
             )
@@ -106,78 +42,14 @@ impl MapRow {
         }
     }
 
-    /// Returns a series of MapRowMappings depending on the range.
-    /// Can return at most three.
-    fn feed_range(&self, range: Range) -> Vec<MapRowMapping> {
-        if range.start < self.source_range_start {
-            // The start of range will be unmapped
-            let unmapped_length = self.source_range_start - range.start;
-            if unmapped_length >= range.length {
-                return vec![MapRowMapping::Unmapped(range)];
-            }
-
-            // `starting` is unmapped. There will be mapped numbers.
-            let starting = MapRowMapping::Unmapped(Range {
-                start: range.start,
-                length: unmapped_length,
-            });
-            let remaining_start = self.source_range_start;
-            let remaining_length = range.length - unmapped_length;
-            if remaining_length <= self.range_length {
-                return vec![
-                    starting,
-                    MapRowMapping::Mapped(Range {
-                        start: self.source_to_dest(remaining_start).unwrap(),
-                        length: remaining_length,
-                    }),
-                ];
-            }
-
-            // `middle` is mapped. There will still be unmapped numbers
-            let middle = MapRowMapping::Mapped(Range {
-                start: self.source_to_dest(remaining_start).unwrap(),
-                length: self.range_length,
-            });
-            let ending_length = remaining_length - self.range_length;
-            let ending_start = remaining_start + self.range_length;
-            return vec![
-                starting,
-                middle,
-                MapRowMapping::Unmapped(Range {
-                    start: ending_start,
-                    length: ending_length,
-                }),
-            ];
-        } else if range.start >= self.source_range_start + self.range_length {
-            // There won't be any mapping at all
-            return vec![MapRowMapping::Unmapped(range)];
-        } else {
-            // The start of range will be mapped
-            let unmapped_length = range.start - self.source_range_start; // to the left of range
-            let self_remaining_length = self.range_length - unmapped_length;
-            if self_remaining_length >= range.length {
-                // All of range will be mapped
-                return vec![MapRowMapping::Mapped(Range {
-                    start: self.source_to_dest(range.start).unwrap(),
-                    length: range.length,
-                })];
-            }
+    /// The sources that this row maps
+    fn source(&self) -> Interval<i64> {
+        Interval::with_length(self.source_range_start, self.range_length)
+    }
 
-            //
-            let mapped = MapRowMapping::Mapped(Range {
-                start: self.source_to_dest(range.start).unwrap(),
-                length: self_remaining_length,
-            });
-            let unmapped_length = range.length - self_remaining_length;
-            let unmapped_start = range.start + self_remaining_length;
-            return vec![
-                mapped,
-                MapRowMapping::Unmapped(Range {
-                    start: unmapped_start,
-                    length: unmapped_length,
-                }),
-            ];
-        }
+    /// How much this row moves a source to its destination
+    fn offset(&self) -> i64 {
+        self.destination_range_start - self.source_range_start
     }
 }
 
@@ -199,48 +71,32 @@ impl Map {
     }
 
     /// Returns the destination given a source. Returns None if it can't be found in the ranges.
-    fn source_to_dest(&self, source: u64) -> Option<u64> {
+    fn source_to_dest(&self, source: i64) -> Option<i64> {
         self.rows.iter().find_map(|row| row.source_to_dest(source))
     }
 
     /// Returns the destination given a source. Returns the same number if it can't be found in the ranges.
-    fn source_to_dest_or_same(&self, source: u64) -> u64 {
+    fn source_to_dest_or_same(&self, source: i64) -> i64 {
         self.source_to_dest(source).unwrap_or(source)
     }
 
-    /// Feed it with a single range naively. Returns a list of ranges.
-    fn feed_range(&self, range: Range) -> Vec<Range> {
-        let mut unmapped_ranges = vec![range];
-        let mut mapped_ranges = vec![];
+    /// Feed it with a set of sources. Returns the set of their destinations.
+    fn feed_ranges(&self, sources: &IntervalSet<i64>) -> IntervalSet<i64> {
+        let mut unmapped = sources.clone();
+        let mut mapped = IntervalSet::new();
         for row in &self.rows {
-            let unmapped_ranges_now = unmapped_ranges;
-            unmapped_ranges = vec![];
-            for range in unmapped_ranges_now {
-                for result in row.feed_range(range) {
-                    match result {
-                        MapRowMapping::Mapped(r) => mapped_ranges.push(r),
-                        MapRowMapping::Unmapped(r) => unmapped_ranges.push(r),
-                    }
-                }
-            }
+            let row_source = IntervalSet::from(row.source());
+            mapped = mapped.union(
+                &unmapped.intersection(&row_source).translate(row.offset()),
+            );
+            unmapped = unmapped.difference(&row_source);
         }
-
-        unmapped_ranges.append(&mut mapped_ranges);
-        merge_ranges(unmapped_ranges)
-    }
-
-    /// Feed it with a bunch of ranges. Returns a list of ranges.
-    fn feed_ranges(&self, ranges: Vec<Range>) -> Vec<Range> {
-        ranges
-            .into_iter()
-            .map(|range| self.feed_range(range))
-            .flatten()
-            .collect()
+        unmapped.union(&mapped)
     }
 }
 
 struct Almanac {
-    seeds: Vec<u64>,
+    seeds: Vec<i64>,
     seed_to_soil: Map,
     soil_to_fertilizer: Map,
     fertilizer_to_water: Map,
@@ -265,9 +121,9 @@ impl Almanac {
             .strip_prefix("seeds: ")
             .context("could not find prefix 'seeds'")?
             .split(' ')
-            .map(|seed| seed.parse::<u64>().ok())
-            .collect::<Option<Vec<u64>>>()
-            .context("could not parse seeds as u64")?;
+            .map(|seed| seed.parse::<i64>().ok())
+            .collect::<Option<Vec<i64>>>()
+            .context("could not parse seeds as i64")?;
 
         let groups = groups
             .into_iter()
@@ -296,7 +152,7 @@ impl Almanac {
     }
 
     /// Using the seeds, return all locations
-    fn get_locations(&self) -> Vec<u64> {
+    fn get_locations(&self) -> Vec<i64> {
         self.seeds
             .iter()
             .map(|seed| self.seed_to_soil.source_to_dest_or_same(*seed))
@@ -317,34 +173,28 @@ impl Almanac {
             .collect()
     }
 
-    /// Using a list of ranges, return all ranges, or an empty list if seeds.len() isn't even
-    fn get_locations_ranges(&self) -> Vec<Range> {
-        let initial_ranges = self
+    /// Using the seeds as pairs of start and length, return the set of all locations,
+    /// or None if seeds.len() isn't even
+    fn get_locations_ranges(&self) -> Option<IntervalSet<i64>> {
+        let seed_ranges = self
             .seeds
             .chunks(2)
-            .map(|pair| {
-                if pair.len() != 2 {
-                    None
-                } else {
-                    Some(Range { start: pair[0], length: pair[1] })
-                }
+            .map(|pair| match pair {
+                [start, length] => Some(Interval::with_length(*start, *length)),
+                _ => None,
             })
-            .collect::<Option<Vec<_>>>();
-        if initial_ranges.is_none() {
-            return vec![];
-        }
-        let seed_ranges = initial_ranges.unwrap();
-        let soil_ranges = self.seed_to_soil.feed_ranges(seed_ranges);
+            .collect::<Option<IntervalSet<_>>>()?;
+        let soil_ranges = self.seed_to_soil.feed_ranges(&seed_ranges);
         let fertilizer_ranges =
-            self.soil_to_fertilizer.feed_ranges(soil_ranges);
+            self.soil_to_fertilizer.feed_ranges(&soil_ranges);
         let water_ranges =
-            self.fertilizer_to_water.feed_ranges(fertilizer_ranges);
-        let light_ranges = self.water_to_light.feed_ranges(water_ranges);
+            self.fertilizer_to_water.feed_ranges(&fertilizer_ranges);
+        let light_ranges = self.water_to_light.feed_ranges(&water_ranges);
         let temperature_ranges =
-            self.light_to_temperature.feed_ranges(light_ranges);
+            self.light_to_temperature.feed_ranges(&light_ranges);
         let humidity_ranges =
-            self.temperature_to_humidity.feed_ranges(temperature_ranges);
-        self.humidity_to_location.feed_ranges(humidity_ranges)
+            self.temperature_to_humidity.feed_ranges(&temperature_ranges);
+        Some(self.humidity_to_location.feed_ranges(&humidity_ranges))
     }
 }
 
@@ -352,59 +202,6 @@ impl Almanac {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_combine() {
-        assert_eq!(
-            combine(
-                Range { start: 1, length: 2 },
-                Range { start: 3, length: 8 }
-            ),
-            None,
-        );
-        assert_eq!(
-            combine(
-                Range { start: 1, length: 3 },
-                Range { start: 3, length: 8 }
-            ),
-            Some(Range { start: 1, length: 10 })
-        );
-        assert_eq!(
-            combine(
-                Range { start: 1, length: 5 },
-                Range { start: 3, length: 8 }
-            ),
-            Some(Range { start: 1, length: 10 })
-        );
-        assert_eq!(
-            combine(
-                Range { start: 1, length: 16 },
-                Range { start: 3, length: 8 }
-            ),
-            Some(Range { start: 1, length: 16 })
-        );
-        assert_eq!(
-            combine(
-                Range { start: 7, length: 3 },
-                Range { start: 8, length: 3 }
-            ),
-            Some(Range { start: 7, length: 4 })
-        );
-    }
-
-    #[test]
-    fn test_merge_ranges() {
-        let ranges = vec![
-            Range { start: 1, length: 3 },
-            Range { start: 2, length: 4 },
-            Range { start: 7, length: 3 },
-            Range { start: 8, length: 3 },
-        ];
-        assert_eq!(
-            merge_ranges(ranges),
-            vec![Range { start: 1, length: 5 }, Range { start: 7, length: 4 }]
-        );
-    }
-
     #[test]
     fn test_map_row() {
         let map_row = MapRow::new("50 98 2").unwrap();
@@ -420,21 +217,6 @@ mod tests {
         assert_eq!(map_row.source_to_dest(98), Some(50));
         assert_eq!(map_row.source_to_dest(99), Some(51));
         assert_eq!(map_row.source_to_dest(100), None);
-
-        // source_range is from 97 to 100
-        let source_range = Range { start: 97, length: 4 };
-        assert_eq!(
-            map_row.feed_range(source_range),
-            vec![
-                MapRowMapping::Unmapped(Range { start: 97, length: 1 }),
-                MapRowMapping::Mapped(Range { start: 50, length: 2 }),
-                MapRowMapping::Unmapped(Range { start: 100, length: 1 }),
-            ]
-        );
-        assert_eq!(
-            map_row.feed_range(Range { start: 100, length: 2 }),
-            vec![MapRowMapping::Unmapped(Range { start: 100, length: 2 })]
-        )
     }
 
     #[test]
@@ -458,6 +240,13 @@ mod tests {
         for (source, dest) in expects {
             assert_eq!(map.source_to_dest_or_same(source), dest)
         }
+
+        // Sources from 97 to 100 map to 99, 50, 51, and 100
+        let sources = IntervalSet::from(Interval::new(97, 101));
+        assert_eq!(
+            map.feed_ranges(&sources).intervals(),
+            &[Interval::new(50, 52), Interval::new(99, 101),]
+        );
     }
 }
 
@@ -471,12 +260,12 @@ fn solve_part_1(almanac: &Almanac) -> Result<String> {
 }
 
 fn solve_part_2(almanac: &Almanac) -> Result<String> {
-    Ok(almanac
+    almanac
         .get_locations_ranges()
-        .iter()
-        .min_by_key(|range| range.start)
-        .and_then(|range| Some(range.start.to_string()))
-        .context("not even number of seeds")?)
+        .context("not even number of seeds")?
+        .min()
+        .map(|location| location.to_string())
+        .context("locations is empty")
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
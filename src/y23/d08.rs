@@ -4,10 +4,12 @@ use anyhow::{bail, Context, Result};
 use num::Integer;
 use sscanf::sscanf;
 
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
 use crate::util::vectors::group;
 
 #[derive(Debug, Clone, Copy)]
-enum Direction {
+pub enum Direction {
     Left,
     Right,
 }
@@ -22,7 +24,7 @@ impl Direction {
     }
 }
 
-type Directions = Vec<Direction>;
+pub type Directions = Vec<Direction>;
 
 type Element = [char; 3];
 
@@ -39,7 +41,7 @@ fn new_element(input: &str) -> Option<Element> {
     }
 }
 
-struct Network {
+pub struct Network {
     nodes: HashMap<Element, (Element, Element)>,
 }
 
@@ -86,7 +88,7 @@ impl Network {
     }
 }
 
-fn solve_part_1(directions: &Directions, network: &Network) -> Result<String> {
+fn solve_part_1(directions: &Directions, network: &Network) -> Result<Answer> {
     let mut step_count = 0;
     let mut source: Element = ['A', 'A', 'A'];
     'l: loop {
@@ -101,10 +103,10 @@ fn solve_part_1(directions: &Directions, network: &Network) -> Result<String> {
             }
         }
     }
-    Ok(step_count.to_string())
+    Ok(step_count.into())
 }
 
-fn solve_part_2(directions: &Directions, network: &Network) -> Result<String> {
+fn solve_part_2(directions: &Directions, network: &Network) -> Result<Answer> {
     let sources = network
         .nodes
         .keys()
@@ -134,27 +136,41 @@ fn solve_part_2(directions: &Directions, network: &Network) -> Result<String> {
         .into_iter()
         .reduce(|v1, v2| v1.lcm(&v2))
         .context("empty step counts")?
-        .to_string())
+        .into())
 }
 
-pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let groups = group(lines);
-    if groups.len() != 2 {
-        bail!("input should be in 2 groups, got {}", groups.len())
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Directions, Network);
+
+    fn parse(lines: Vec<String>) -> Result<(Directions, Network)> {
+        let groups = group(lines);
+        if groups.len() != 2 {
+            bail!("input should be in 2 groups, got {}", groups.len())
+        }
+        let (directions, network) = (&groups[0], &groups[1]);
+        if directions.len() != 1 {
+            bail!(
+                "expect directions to be only 1 line, got {}",
+                directions.len()
+            );
+        }
+        let directions = directions[0]
+            .chars()
+            .map(|c| Direction::new(c))
+            .collect::<Option<Vec<_>>>()
+            .context("cannot parse directions")?;
+        let network = Network::new(network).context("cannot parse network")?;
+
+        Ok((directions, network))
     }
-    let (directions, network) = (&groups[0], &groups[1]);
-    if directions.len() != 1 {
-        bail!("expect directions to be only 1 line, got {}", directions.len());
+
+    fn part1((directions, network): &(Directions, Network)) -> Result<Answer> {
+        solve_part_1(directions, network)
+    }
+
+    fn part2((directions, network): &(Directions, Network)) -> Result<Answer> {
+        solve_part_2(directions, network)
     }
-    let directions = directions[0]
-        .chars()
-        .map(|c| Direction::new(c))
-        .collect::<Option<Vec<_>>>()
-        .context("cannot parse directions")?;
-    let network = Network::new(network).context("cannot parse network")?;
-
-    Ok((
-        solve_part_1(&directions, &network),
-        solve_part_2(&directions, &network),
-    ))
 }
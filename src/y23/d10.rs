@@ -1,8 +1,9 @@
-use std::{collections::HashSet, fmt};
+use std::fmt;
 
 use anyhow::{bail, Context, Result};
 
-use crate::util::vectors::Grid;
+use crate::util::geom::{self, Direction, Point};
+use crate::util::grid::Grid;
 
 #[derive(Clone, Copy)]
 enum Tile {
@@ -67,54 +68,17 @@ impl fmt::Display for Tile {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-
-impl Direction {
-    fn rev(&self) -> Direction {
-        match self {
-            Direction::North => Self::South,
-            Direction::South => Self::North,
-            Direction::West => Self::East,
-            Direction::East => Self::West,
-        }
-    }
-}
-
 struct Maze {
-    tiles: Vec<Vec<Tile>>,
-    width: usize,
-    height: usize,
-}
-
-#[derive(PartialEq, Eq, Debug)]
-enum MazeRowState {
-    Outside,
-    Inside,
-    ToInsideFrom(Direction),
-    ToOutsideFrom(Direction),
+    tiles: Grid<Tile>,
 }
 
 impl Maze {
-    fn new(grid: &Grid) -> Option<Maze> {
-        let tiles = grid
-            .chars
-            .iter()
-            .map(|line| {
-                line.iter().map(|c| Tile::new(*c)).collect::<Option<Vec<_>>>()
-            })
-            .collect::<Option<Vec<Vec<_>>>>()?;
-        Some(Maze { tiles, width: grid.width, height: grid.height })
+    fn new(lines: &[String]) -> Result<Maze> {
+        Ok(Maze { tiles: Grid::parse_with(lines, Tile::new)? })
     }
 
-    /// at((pos_x,pos_y)) is shorthand for tiles[pos_y][pos_x].
-    fn at(&self, (pos_x, pos_y): (usize, usize)) -> Tile {
-        self.tiles[pos_y][pos_x]
+    fn at(&self, pos: (usize, usize)) -> Tile {
+        self.tiles[pos]
     }
 
     /// Returns the farthest distance from starting position.
@@ -175,8 +139,8 @@ impl Maze {
             .context("cannot find starting position")?;
         let mut direction = Direction::East; // doesn't matter; next_move will get a valid direction for us
 
-        let mut border_positions = HashSet::new();
-        border_positions.insert(position);
+        // The loop is a polygon whose vertices are the tiles along it, in order
+        let mut loop_positions = vec![Point::from_grid_pos(position)];
 
         loop {
             let (next_direction, next_position) =
@@ -188,33 +152,10 @@ impl Maze {
                 break;
             }
             (position, direction) = (next_position, next_direction);
-            border_positions.insert(position);
+            loop_positions.push(Point::from_grid_pos(position));
         }
 
-        let mut count_tiles = 0;
-        for pos_y in 0..self.height {
-            // Every row begins in being "outside" the border
-            let mut current_row_state = MazeRowState::Outside;
-            for pos_x in 0..self.width {
-                if border_positions.contains(&(pos_x, pos_y)) {
-                    current_row_state = self
-                        .next_row_state(
-                            current_row_state,
-                            self.at((pos_x, pos_y)),
-                        )
-                        .context(format!(
-                            "cannot get next row state at {} {}",
-                            pos_x, pos_y
-                        ))?;
-                }
-                if current_row_state == MazeRowState::Inside
-                    && !border_positions.contains(&(pos_x, pos_y))
-                {
-                    count_tiles += 1;
-                }
-            }
-        }
-        Ok(count_tiles)
+        Ok(geom::interior_points(&loop_positions) as usize)
     }
 
     /// next_move(West, (pos_x, pos_y)) evaluates what the next move should be when entering (pos_x,pos_y) from the West.
@@ -272,141 +213,26 @@ impl Maze {
         }
     }
 
-    /// Returns (pos_x, pos_y) such that self.tiles[pos_y][pos_x] == Tile::Starting
+    /// Returns (pos_x, pos_y) such that self.at((pos_x, pos_y)) == Tile::Starting
     fn get_starting_position(&self) -> Result<(usize, usize)> {
-        for pos_x in 0..self.width {
-            for pos_y in 0..self.height {
-                if self.tiles[pos_y][pos_x] == Tile::Starting {
-                    return Ok((pos_x, pos_y));
-                }
-            }
-        }
-        bail!("cannot find starting tile")
+        self.tiles
+            .find(|tile| *tile == Tile::Starting)
+            .context("cannot find starting tile")
     }
 
     /// Evaluates if it's possible to go to Direction from (pos_x,pos_y) and returns the new position if so
     fn _check_next_pos(
         &self,
         direction: Direction,
-        (pos_x, pos_y): (usize, usize),
+        pos: (usize, usize),
     ) -> Option<(usize, usize)> {
-        match direction {
-            Direction::North => {
-                if pos_y >= 1 {
-                    return Some((pos_x, pos_y - 1));
-                }
-            }
-            Direction::South => {
-                if pos_y + 1 < self.height {
-                    return Some((pos_x, pos_y + 1));
-                }
-            }
-            Direction::West => {
-                if pos_x >= 1 {
-                    return Some((pos_x - 1, pos_y));
-                }
-            }
-            Direction::East => {
-                if pos_x + 1 < self.width {
-                    return Some((pos_x + 1, pos_y));
-                }
-            }
-        }
-        None
-    }
-
-    /// Gets the next row previous_row_state, given the previous one, and the current position
-    fn next_row_state(
-        &self,
-        previous_row_state: MazeRowState,
-        current_tile: Tile,
-    ) -> Result<MazeRowState> {
-        // The situation may only flip when there's a single vertical pipe │
-        // or a continuous series e.g., └┐, └──┐.
-        match (&previous_row_state, current_tile) {
-            (MazeRowState::Outside, Tile::Pipe(_, _)) => {
-                let has_north = current_tile.has_direction(Direction::North);
-                let has_south = current_tile.has_direction(Direction::South);
-                match (has_north, has_south) {
-                    (true, true) => Ok(MazeRowState::Inside),
-                    (true, false) => {
-                        Ok(MazeRowState::ToInsideFrom(Direction::North))
-                    }
-                    (false, true) => {
-                        Ok(MazeRowState::ToInsideFrom(Direction::South))
-                    }
-                    (false, false) => bail!(
-                        "cannot enter from outside to tile {}",
-                        current_tile,
-                    ),
-                }
-            }
-            (MazeRowState::Inside, Tile::Pipe(_, _)) => {
-                let has_north = current_tile.has_direction(Direction::North);
-                let has_south = current_tile.has_direction(Direction::South);
-                match (has_north, has_south) {
-                    (true, true) => Ok(MazeRowState::Outside),
-                    (true, false) => {
-                        Ok(MazeRowState::ToOutsideFrom(Direction::North))
-                    }
-                    (false, true) => {
-                        Ok(MazeRowState::ToOutsideFrom(Direction::South))
-                    }
-                    (false, false) => bail!(
-                        "cannot enter from inside to tile {}",
-                        current_tile,
-                    ),
-                }
-            }
-            (MazeRowState::ToInsideFrom(d), Tile::Pipe(_, _)) => {
-                let has_north = current_tile.has_direction(Direction::North);
-                let has_south = current_tile.has_direction(Direction::South);
-                if has_north && *d == Direction::South
-                    || has_south && *d == Direction::North
-                {
-                    Ok(MazeRowState::Inside)
-                } else if has_north && *d == Direction::North
-                    || has_south && *d == Direction::South
-                {
-                    Ok(MazeRowState::Outside)
-                } else {
-                    Ok(previous_row_state)
-                }
-            }
-            (MazeRowState::ToOutsideFrom(d), Tile::Pipe(_, _)) => {
-                let has_north = current_tile.has_direction(Direction::North);
-                let has_south = current_tile.has_direction(Direction::South);
-                if has_north && *d == Direction::South
-                    || has_south && *d == Direction::North
-                {
-                    Ok(MazeRowState::Outside)
-                } else if has_north && *d == Direction::North
-                    || has_south && *d == Direction::South
-                {
-                    Ok(MazeRowState::Inside)
-                } else {
-                    Ok(previous_row_state)
-                }
-            }
-            (_, Tile::Ground) => Ok(previous_row_state),
-            (_, Tile::Starting) => self.next_row_state(
-                previous_row_state,
-                self.infer_starting_tile()
-                    .context("cannot get starting tile")?,
-            ),
-        }
+        self.tiles.step(pos, direction)
     }
 }
 
 impl fmt::Display for Maze {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for row in &self.tiles {
-            for tile in row {
-                write!(f, "{}", tile)?;
-            }
-            write!(f, "\n")?;
-        }
-        return fmt::Result::Ok(());
+        write!(f, "{}", self.tiles)
     }
 }
 
@@ -425,8 +251,7 @@ fn solve_part_2(maze: &Maze) -> Result<String> {
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let grid = Grid::new(&lines).context("cannot create grid")?;
-    let maze = Maze::new(&grid).context("cannot parse grid to cells")?;
+    let maze = Maze::new(&lines).context("cannot parse grid to cells")?;
 
     Ok((solve_part_1(&maze), solve_part_2(&maze)))
 }
@@ -2,7 +2,7 @@ use std::{collections::HashSet, fmt::Display};
 
 use anyhow::{Context, Result};
 
-use crate::util::vectors::Grid;
+use crate::util::grid::Grid;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Elem {
@@ -31,69 +31,38 @@ impl Display for Elem {
 }
 
 struct Image {
-    grid: Vec<Vec<Elem>>,
-    width: usize,
-    height: usize,
+    grid: Grid<Elem>,
     empty_rows: HashSet<usize>,
     empty_cols: HashSet<usize>,
 }
 
 impl Image {
-    fn new(grid: Grid) -> Result<Image> {
-        let elem_grid = grid
-            .chars
-            .into_iter()
-            .map(|row| {
-                row.into_iter()
-                    .map(|c| Elem::new(c))
-                    .collect::<Option<Vec<_>>>()
-            })
-            .collect::<Option<Vec<_>>>()
+    fn new(lines: &[String]) -> Result<Image> {
+        let grid = Grid::parse_with(lines, Elem::new)
             .context("cannot parse grid: invalid characters")?;
-        let mut empty_rows = HashSet::new();
-        'outer: for pos_y in 0..grid.height {
-            for pos_x in 0..grid.width {
-                if elem_grid[pos_y][pos_x] == Elem::Galaxy {
-                    continue 'outer;
-                }
-            }
-            empty_rows.insert(pos_y);
-        }
-        let mut empty_cols = HashSet::new();
-        'outer: for pos_x in 0..grid.width {
-            for pos_y in 0..grid.height {
-                if elem_grid[pos_y][pos_x] == Elem::Galaxy {
-                    continue 'outer;
-                }
-            }
-            empty_cols.insert(pos_x);
-        }
-
-        Ok(Image {
-            grid: elem_grid,
-            width: grid.width,
-            height: grid.height,
-            empty_rows,
-            empty_cols,
-        })
-    }
-
-    fn at(&self, (pos_x, pos_y): (usize, usize)) -> Elem {
-        self.grid[pos_y][pos_x]
+        let empty_rows = grid
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| !row.contains(&Elem::Galaxy))
+            .map(|(pos_y, _)| pos_y)
+            .collect();
+        let empty_cols = (0..grid.width())
+            .filter(|&pos_x| grid.column(pos_x).all(|e| *e != Elem::Galaxy))
+            .collect();
+
+        Ok(Image { grid, empty_rows, empty_cols })
     }
 
     /// Gets the sum of shortest paths, where empty_distance is the size of the empty rows.
     /// That is, in Part 1, empty_distance was 2, while in Part 2 it would be 1_000_000.
     fn get_sum_of_shortest_paths(&self, empty_distance: usize) -> usize {
         // Grab all positions of galaxies
-        let mut positions = vec![];
-        for pos_x in 0..self.width {
-            for pos_y in 0..self.height {
-                if self.at((pos_x, pos_y)) == Elem::Galaxy {
-                    positions.push((pos_x, pos_y));
-                }
-            }
-        }
+        let positions = self
+            .grid
+            .iter()
+            .filter(|(_, elem)| **elem == Elem::Galaxy)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
 
         // Then, grab the list of rows and columns which contain galaxies
         // (and how many galaxies there are in that row/col)
@@ -173,13 +142,7 @@ impl Image {
 
 impl Display for Image {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.grid {
-            for col in row {
-                write!(f, "{}", col)?
-            }
-            writeln!(f)?
-        }
-        std::fmt::Result::Ok(())
+        writeln!(f, "{}", self.grid)
     }
 }
 
@@ -192,8 +155,7 @@ fn solve_part_2(image: &Image) -> Result<String> {
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let grid = Grid::new(&lines).context("cannot create grid")?;
-    let image = Image::new(grid).context("cannot create image")?;
+    let image = Image::new(&lines).context("cannot create image")?;
 
     Ok((solve_part_1(&image), solve_part_2(&image)))
 }
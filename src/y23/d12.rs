@@ -2,6 +2,8 @@ use std::fmt::{Debug, Display};
 
 use anyhow::{Context, Result};
 
+use crate::trace;
+
 #[derive(PartialEq, Eq, Clone, Copy, Hash)]
 enum Condition {
     Operational,
@@ -320,8 +322,13 @@ mod tests {
         let cases =
             cases.into_iter().map(|(s, c)| (Springs::new(s).unwrap(), c));
         for (springs, count) in cases {
-            println!("{:?} must have {:?} arrangements", &springs, count);
-            assert_eq!(springs.count_arrangements(), count)
+            assert_eq!(
+                springs.count_arrangements(),
+                count,
+                "{:?} must have {:?} arrangements",
+                &springs,
+                count
+            )
         }
     }
 
@@ -337,8 +344,13 @@ mod tests {
         let cases =
             cases.into_iter().map(|(s, c)| (Springs::new(s).unwrap(), c));
         for (springs, count) in cases {
-            println!("{:?} must have {:?} arrangements", &springs, count);
-            assert_eq!(springs.count_arrangements(), count)
+            assert_eq!(
+                springs.count_arrangements(),
+                count,
+                "{:?} must have {:?} arrangements",
+                &springs,
+                count
+            )
         }
     }
 
@@ -364,8 +376,9 @@ fn solve_part_1(all_springs: &Vec<Springs>) -> Result<String> {
     let mut result = 0;
     for springs in all_springs {
         let current_count = springs.count_arrangements();
-        println!(
-            "{} {}",
+        trace!(
+            Debug,
+            "{} has {} arrangements",
             Springs::pretty_print_condition_records_and_damaged_groups(
                 &springs.condition_records,
                 &springs.damaged_groups
@@ -381,14 +394,17 @@ fn solve_part_2(all_springs: Vec<Springs>) -> Result<String> {
     let mut result = 0;
     for mut springs in all_springs {
         springs.unfold();
-        println!(
-            "{}",
+        let current_count = springs.count_arrangements();
+        trace!(
+            Debug,
+            "{} has {} arrangements",
             Springs::pretty_print_condition_records_and_damaged_groups(
                 &springs.condition_records,
                 &springs.damaged_groups
-            )
+            ),
+            current_count
         );
-        result += springs.count_arrangements();
+        result += current_count;
     }
     Ok(result.to_string())
 }
@@ -2,7 +2,8 @@ use std::fmt;
 
 use anyhow::{Context, Ok, Result};
 
-use crate::util::vectors::{group, Grid};
+use crate::util::grid::Grid;
+use crate::util::vectors::group;
 
 /// Mirror determines where the mirror is in a Pattern.
 /// Vertical(pos_x) means that Pattern.at(pos_x,*) == Pattern.at(pos_x+1,*);  Pattern.at(pos_x-1,*) == Pattern.at(pos_x+2,*), etc.
@@ -40,149 +41,58 @@ impl fmt::Display for Cell {
 }
 
 struct Pattern {
-    grid: Vec<Vec<Cell>>,
-    height: usize,
-    width: usize,
+    grid: Grid<Cell>,
 }
 
 impl Pattern {
-    fn new(grid: &Grid) -> Option<Pattern> {
-        let cell_grid = grid
-            .chars
-            .iter()
-            .map(|row| {
-                row.iter().map(|c| Cell::new(*c)).collect::<Option<Vec<_>>>()
-            })
-            .collect::<Option<Vec<_>>>()?;
-        Some(Pattern {
-            grid: cell_grid,
-            height: grid.height,
-            width: grid.width,
-        })
-    }
-
-    fn at(&self, (pos_x, pos_y): (usize, usize)) -> Cell {
-        self.grid[pos_y][pos_x]
+    fn new(lines: &[String]) -> Result<Pattern> {
+        Ok(Pattern { grid: Grid::parse_with(lines, Cell::new)? })
     }
 
     fn find_mirror(&self) -> Option<Mirror> {
-        for candidate_pos_x in 0..self.width - 1 {
-            if self.check_vertical_mirror(candidate_pos_x) {
-                return Some(Mirror::Vertical(candidate_pos_x));
-            }
-        }
-        for candidate_pos_y in 0..self.height - 1 {
-            if self.check_horizontal_mirror(candidate_pos_y) {
-                return Some(Mirror::Horizontal(candidate_pos_y));
-            }
-        }
-
-        None
+        self.find_mirror_with_smudges(0)
     }
 
     fn find_smudged_mirror(&self) -> Option<Mirror> {
-        for candidate_pos_x in 0..self.width - 1 {
-            if self.check_smudged_vertical_mirror(candidate_pos_x) {
-                return Some(Mirror::Vertical(candidate_pos_x));
-            }
-        }
-        for candidate_pos_y in 0..self.height - 1 {
-            if self.check_smudged_horizontal_mirror(candidate_pos_y) {
-                return Some(Mirror::Horizontal(candidate_pos_y));
-            }
-        }
-
-        None
+        self.find_mirror_with_smudges(1)
     }
 
-    /// Checks if it's possible that the vertical mirrror is at some position
-    fn check_vertical_mirror(&self, candidate_pos_x: usize) -> bool {
-        for ii in 0.. {
-            if candidate_pos_x < ii || candidate_pos_x + 1 + ii >= self.width {
-                break;
-            }
-            let (left_pos_x, right_pos_x) =
-                (candidate_pos_x - ii, candidate_pos_x + 1 + ii);
-            for pos_y in 0..self.height {
-                if self.at((left_pos_x, pos_y)) != self.at((right_pos_x, pos_y))
-                {
-                    return false;
-                }
-            }
+    /// Finds a mirror where exactly `smudges` cells differ from their reflection.
+    /// Vertical mirrors are checked first, as horizontal mirrors of the transposed Pattern.
+    fn find_mirror_with_smudges(&self, smudges: usize) -> Option<Mirror> {
+        if let Some(pos_x) =
+            Self::find_horizontal_mirror(&self.grid.transpose(), smudges)
+        {
+            return Some(Mirror::Vertical(pos_x));
         }
-        true
+        Self::find_horizontal_mirror(&self.grid, smudges)
+            .map(Mirror::Horizontal)
     }
 
-    /// Checks if it's possible that the horizontal mirrror is at some position
-    fn check_horizontal_mirror(&self, candidate_pos_y: usize) -> bool {
-        for ii in 0.. {
-            if candidate_pos_y < ii || candidate_pos_y + 1 + ii >= self.height {
-                break;
-            }
-            let (up_pos_y, down_pos_y) =
-                (candidate_pos_y - ii, candidate_pos_y + 1 + ii);
-            for pos_x in 0..self.width {
-                if self.at((pos_x, up_pos_y)) != self.at((pos_x, down_pos_y)) {
-                    return false;
-                }
-            }
-        }
-        true
-    }
-
-    /// Checks if it's possible that the smudged vertical mirrror is at some position
-    fn check_smudged_vertical_mirror(&self, candidate_pos_x: usize) -> bool {
-        let mut mistakes = 0;
-        for ii in 0.. {
-            if candidate_pos_x < ii || candidate_pos_x + 1 + ii >= self.width {
-                break;
-            }
-            let (left_pos_x, right_pos_x) =
-                (candidate_pos_x - ii, candidate_pos_x + 1 + ii);
-            for pos_y in 0..self.height {
-                if self.at((left_pos_x, pos_y)) != self.at((right_pos_x, pos_y))
-                {
-                    mistakes += 1;
-                }
-                if mistakes >= 2 {
-                    return false;
-                }
-            }
-        }
-        mistakes == 1
-    }
-
-    /// Checks if it's possible that the smudged horizontal mirrror is at some position
-    fn check_smudged_horizontal_mirror(&self, candidate_pos_y: usize) -> bool {
-        let mut mistakes = 0;
-        for ii in 0.. {
-            if candidate_pos_y < ii || candidate_pos_y + 1 + ii >= self.height {
-                break;
-            }
-            let (up_pos_y, down_pos_y) =
-                (candidate_pos_y - ii, candidate_pos_y + 1 + ii);
-            for pos_x in 0..self.width {
-                if self.at((pos_x, up_pos_y)) != self.at((pos_x, down_pos_y)) {
-                    mistakes += 1;
-                }
-                if mistakes >= 2 {
-                    return false;
-                }
-            }
-        }
-        mistakes == 1
+    /// Finds pos_y such that the rows above pos_y+1 reflect the rows below it,
+    /// with exactly `smudges` cells that differ from their reflection.
+    fn find_horizontal_mirror(
+        grid: &Grid<Cell>,
+        smudges: usize,
+    ) -> Option<usize> {
+        let rows = grid.rows().collect::<Vec<_>>();
+        (0..rows.len() - 1).find(|&candidate_pos_y| {
+            let mistakes = rows[..=candidate_pos_y]
+                .iter()
+                .rev()
+                .zip(&rows[candidate_pos_y + 1..])
+                .map(|(up, down)| {
+                    up.iter().zip(down.iter()).filter(|(a, b)| a != b).count()
+                })
+                .sum::<usize>();
+            mistakes == smudges
+        })
     }
 }
 
 impl fmt::Display for Pattern {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for pos_y in 0..self.height {
-            for pos_x in 0..self.width {
-                write!(f, "{}", self.at((pos_x, pos_y)))?
-            }
-            writeln!(f, "")?
-        }
-        fmt::Result::Ok(())
+        writeln!(f, "{}", self.grid)
     }
 }
 
@@ -191,13 +101,7 @@ mod tests {
     use super::*;
     fn pattern_from_str(grid: &str) -> Pattern {
         Pattern::new(
-            &Grid::new(
-                &grid
-                    .split('\n')
-                    .map(|s| s.to_owned())
-                    .collect::<Vec<String>>(),
-            )
-            .unwrap(),
+            &grid.split('\n').map(|s| s.to_owned()).collect::<Vec<String>>(),
         )
         .unwrap()
     }
@@ -294,8 +198,8 @@ fn solve_part_2(patterns: &Vec<Pattern>) -> Result<String> {
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
     let patterns = group(lines)
         .iter()
-        .map(|group| Grid::new(group).and_then(|grid| Pattern::new(&grid)))
-        .collect::<Option<Vec<_>>>()
+        .map(|group| Pattern::new(group))
+        .collect::<Result<Vec<_>>>()
         .context("cannot parse all input")?;
     Ok((solve_part_1(&patterns), solve_part_2(&patterns)))
 }
@@ -6,7 +6,7 @@ use std::{
 use anyhow::{Context, Ok, Result};
 use bimap::{BiHashMap, BiMap};
 
-use crate::util::vectors::Grid;
+use crate::util::grid::Grid;
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash)]
 enum Cell {
@@ -26,7 +26,7 @@ impl Cell {
     }
 }
 
-impl Debug for Cell {
+impl fmt::Display for Cell {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let c = match self {
             Cell::RoundedRock => 'O',
@@ -39,43 +39,30 @@ impl Debug for Cell {
 
 #[derive(PartialEq, Eq, Clone)]
 struct Platform {
-    grid: Vec<Vec<Cell>>,
-    width: usize,
-    height: usize,
+    grid: Grid<Cell>,
 }
 
 impl Platform {
-    fn new(grid: Grid) -> Option<Platform> {
-        let cell_grid = grid
-            .chars
-            .iter()
-            .map(|row| {
-                row.iter().map(|c| Cell::new(*c)).collect::<Option<Vec<_>>>()
-            })
-            .collect::<Option<Vec<_>>>()?;
-        Some(Platform {
-            grid: cell_grid,
-            width: grid.width,
-            height: grid.height,
-        })
+    fn new(lines: &[String]) -> Result<Platform> {
+        Ok(Platform { grid: Grid::parse_with(lines, Cell::new)? })
     }
 
-    fn at(&self, (pos_x, pos_y): (usize, usize)) -> Cell {
-        self.grid[pos_y][pos_x]
+    fn at(&self, pos: (usize, usize)) -> Cell {
+        self.grid[pos]
     }
 
-    fn set(&mut self, (pos_x, pos_y): (usize, usize), cell: Cell) {
-        self.grid[pos_y][pos_x] = cell;
+    fn set(&mut self, pos: (usize, usize), cell: Cell) {
+        self.grid[pos] = cell;
     }
 
     fn tilt_north(&mut self) {
-        for pos_x in 0..self.width {
+        for pos_x in 0..self.grid.width() {
             // First, grab all cube-shaped positions
-            let mut cube_positions = (0..self.height)
+            let mut cube_positions = (0..self.grid.height())
                 .into_iter()
                 .filter(|pos_y| self.at((pos_x, *pos_y)) == Cell::CubicRock)
                 .collect::<Vec<_>>();
-            cube_positions.push(self.height);
+            cube_positions.push(self.grid.height());
             let starting_cube_position = cube_positions[0];
             let mut ranges = cube_positions
                 .windows(2)
@@ -102,13 +89,13 @@ impl Platform {
     }
 
     fn tilt_west(&mut self) {
-        for pos_y in 0..self.height {
+        for pos_y in 0..self.grid.height() {
             // First, grab all cube-shaped positions
-            let mut cube_positions = (0..self.width)
+            let mut cube_positions = (0..self.grid.width())
                 .into_iter()
                 .filter(|pos_x| self.at((*pos_x, pos_y)) == Cell::CubicRock)
                 .collect::<Vec<_>>();
-            cube_positions.push(self.width);
+            cube_positions.push(self.grid.width());
             let starting_cube_position = cube_positions[0];
             let mut ranges = cube_positions
                 .windows(2)
@@ -135,13 +122,13 @@ impl Platform {
     }
 
     fn tilt_south(&mut self) {
-        for pos_x in 0..self.width {
+        for pos_x in 0..self.grid.width() {
             // First, grab all cube-shaped positions
-            let mut cube_positions: Vec<usize> = (0..self.height)
+            let mut cube_positions: Vec<usize> = (0..self.grid.height())
                 .into_iter()
                 .filter(|pos_y| self.at((pos_x, *pos_y)) == Cell::CubicRock)
                 .collect::<Vec<_>>();
-            cube_positions.push(self.height);
+            cube_positions.push(self.grid.height());
 
             for ii in 0..cube_positions.len() {
                 let end_pos_y = cube_positions[ii];
@@ -162,13 +149,13 @@ impl Platform {
     }
 
     fn tilt_east(&mut self) {
-        for pos_y in 0..self.height {
+        for pos_y in 0..self.grid.height() {
             // First, grab all cube-shaped positions (this is in reverse)
-            let mut cube_positions = (0..self.width)
+            let mut cube_positions = (0..self.grid.width())
                 .into_iter()
                 .filter(|pos_x| self.at((*pos_x, pos_y)) == Cell::CubicRock)
                 .collect::<Vec<_>>();
-            cube_positions.push(self.width);
+            cube_positions.push(self.grid.width());
 
             for ii in 0..cube_positions.len() {
                 let end_pos_x = cube_positions[ii];
@@ -190,7 +177,7 @@ impl Platform {
 
     fn run_spin_cycle(&mut self, times: usize) {
         // memo contains the state of self before instance ii.
-        let mut memo: BiHashMap<Vec<Vec<Cell>>, usize> = BiMap::new();
+        let mut memo: BiHashMap<Grid<Cell>, usize> = BiMap::new();
         for ii in 0..times {
             self.tilt_north();
             self.tilt_west();
@@ -219,10 +206,10 @@ impl Platform {
 
     fn calculate_total_load(&self) -> u64 {
         let mut total_load = 0;
-        for pos_y in 0..self.height {
-            for pos_x in 0..self.width {
+        for pos_y in 0..self.grid.height() {
+            for pos_x in 0..self.grid.width() {
                 if self.at((pos_x, pos_y)) == Cell::RoundedRock {
-                    total_load += self.height - pos_y;
+                    total_load += self.grid.height() - pos_y;
                 }
             }
         }
@@ -232,13 +219,7 @@ impl Platform {
 
 impl Debug for Platform {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for pos_y in 0..self.height {
-            for pos_x in 0..self.width {
-                write!(f, "{:?}", self.at((pos_x, pos_y)))?;
-            }
-            writeln!(f, "")?;
-        }
-        fmt::Result::Ok(())
+        writeln!(f, "{}", self.grid)
     }
 }
 
@@ -249,7 +230,7 @@ mod tests {
     fn platform_from_str(input: &str) -> Platform {
         let input =
             input.split('\n').map(|s| s.to_string()).collect::<Vec<String>>();
-        Platform::new(Grid::new(&input).unwrap()).unwrap()
+        Platform::new(&input).unwrap()
     }
 
     const EXAMPLE_PLATFORM: &str = "O....#....
@@ -368,8 +349,6 @@ fn solve_part_2(platform: &Platform) -> Result<String> {
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let platform =
-        Platform::new(Grid::new(&lines).context("cannot parse as grid")?)
-            .context("cannot parse as platform")?;
+    let platform = Platform::new(&lines).context("cannot parse as platform")?;
     Ok((solve_part_1(&platform), solve_part_2(&platform)))
 }
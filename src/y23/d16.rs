@@ -4,7 +4,8 @@ use std::{
     fmt,
 };
 
-use crate::util::vectors::Grid;
+use crate::util::geom::Direction;
+use crate::util::grid::Grid;
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 enum Cell {
@@ -47,13 +48,6 @@ impl fmt::Display for Cell {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Hash)]
-enum Direction {
-    North,
-    West,
-    South,
-    East,
-}
 #[derive(PartialEq, Eq, Clone, Hash)]
 struct Step {
     pos: (usize, usize),
@@ -61,29 +55,21 @@ struct Step {
 }
 
 struct Layout {
-    grid: Vec<Vec<Cell>>,
-    height: usize,
-    width: usize,
+    grid: Grid<Cell>,
 }
 
 impl Layout {
-    fn new(grid: Grid) -> Option<Layout> {
-        let cell_grid = grid
-            .chars
-            .iter()
-            .map(|row| {
-                row.iter().map(|c| Cell::new(*c)).collect::<Option<Vec<_>>>()
-            })
-            .collect::<Option<Vec<_>>>()?;
-        Some(Layout { grid: cell_grid, height: grid.height, width: grid.width })
+    fn new(lines: &[String]) -> Result<Layout> {
+        Ok(Layout { grid: Grid::parse_with(lines, Cell::new)? })
     }
 
-    fn at(&self, (pos_x, pos_y): (usize, usize)) -> Cell {
-        self.grid[pos_y][pos_x]
+    fn at(&self, pos: (usize, usize)) -> Cell {
+        self.grid[pos]
     }
 
     fn count_energized_from(&self, first_step: Step) -> usize {
-        let mut energized_cells = vec![vec![false; self.width]; self.height];
+        let mut energized_cells =
+            Grid::filled(self.grid.width(), self.grid.height(), false);
         let mut steps = VecDeque::new();
         steps.push_back(first_step.clone());
         let mut steps_to_be_done = HashSet::new();
@@ -92,48 +78,8 @@ impl Layout {
 
         // Calculates what the next step should be after moving to step.direction
         let next_step = |step: &Step| -> Option<Step> {
-            match step.direction {
-                Direction::North => {
-                    if step.pos.1 == 0 {
-                        None
-                    } else {
-                        Some(Step {
-                            pos: (step.pos.0, step.pos.1 - 1),
-                            direction: step.direction,
-                        })
-                    }
-                }
-                Direction::West => {
-                    if step.pos.0 == 0 {
-                        None
-                    } else {
-                        Some(Step {
-                            pos: (step.pos.0 - 1, step.pos.1),
-                            direction: step.direction,
-                        })
-                    }
-                }
-                Direction::South => {
-                    if step.pos.1 + 1 >= self.height {
-                        None
-                    } else {
-                        Some(Step {
-                            pos: (step.pos.0, step.pos.1 + 1),
-                            direction: step.direction,
-                        })
-                    }
-                }
-                Direction::East => {
-                    if step.pos.0 + 1 >= self.width {
-                        None
-                    } else {
-                        Some(Step {
-                            pos: (step.pos.0 + 1, step.pos.1),
-                            direction: step.direction,
-                        })
-                    }
-                }
-            }
+            let pos = self.grid.step(step.pos, step.direction)?;
+            Some(Step { pos, direction: step.direction })
         };
 
         loop {
@@ -141,7 +87,7 @@ impl Layout {
                 break;
             }
             let step = steps.pop_front().unwrap();
-            energized_cells[step.pos.1][step.pos.0] = true;
+            energized_cells[step.pos] = true;
             steps_to_be_done.remove(&step);
             completed_steps.insert(step.clone());
 
@@ -213,10 +159,7 @@ impl Layout {
             }
         }
 
-        energized_cells
-            .iter()
-            .map(|row| row.iter().filter(|b| **b).count())
-            .sum()
+        energized_cells.iter().filter(|(_, b)| **b).count()
     }
 }
 
@@ -227,11 +170,11 @@ fn solve_part_1(layout: &Layout) -> Result<String> {
 
 fn solve_part_2(layout: &Layout) -> Result<String> {
     let mut first_steps = vec![];
-    for pos_x in 0..layout.width {
+    for pos_x in 0..layout.grid.width() {
         first_steps.push(Step { pos: (pos_x, 0), direction: Direction::North });
         first_steps.push(Step { pos: (pos_x, 0), direction: Direction::South });
     }
-    for pos_y in 0..layout.height {
+    for pos_y in 0..layout.grid.height() {
         first_steps.push(Step { pos: (0, pos_y), direction: Direction::West });
         first_steps.push(Step { pos: (0, pos_y), direction: Direction::East });
     }
@@ -245,8 +188,7 @@ fn solve_part_2(layout: &Layout) -> Result<String> {
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let layout = Layout::new(Grid::new(&lines).context("cannot create grid")?)
-        .context("cannot create layout")?;
+    let layout = Layout::new(&lines).context("cannot create layout")?;
 
     Ok((solve_part_1(&layout), solve_part_2(&layout)))
 }
@@ -0,0 +1,123 @@
+use anyhow::{Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::geom::Direction;
+use crate::util::grid::Grid;
+use crate::util::search;
+use crate::util::solution::Solution;
+
+/// Where a crucible is, which way it last moved,
+/// and how many blocks it has moved in a straight line since it last turned.
+/// Direction is None before the crucible first moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Crucible {
+    pos: (usize, usize),
+    direction: Option<Direction>,
+    run: usize,
+}
+
+pub struct City {
+    heat_losses: Grid<u32>,
+}
+
+impl City {
+    fn new(lines: &[String]) -> Result<City> {
+        let heat_losses = Grid::parse_with(lines, |c| c.to_digit(10))?;
+        Ok(City { heat_losses })
+    }
+
+    /// Crucibles that can be reached by moving one block from crucible,
+    /// if a crucible has to move min_run to max_run blocks before turning
+    fn moves(
+        &self,
+        crucible: Crucible,
+        (min_run, max_run): (usize, usize),
+    ) -> Vec<(Crucible, u32)> {
+        let directions = match crucible.direction {
+            None => Direction::ALL.to_vec(),
+            Some(direction) => {
+                let mut directions = vec![];
+                if crucible.run < max_run {
+                    directions.push(direction);
+                }
+                if crucible.run >= min_run {
+                    directions.push(direction.turn_left());
+                    directions.push(direction.turn_right());
+                }
+                directions
+            }
+        };
+        directions
+            .into_iter()
+            .filter_map(|direction| {
+                let pos = self.heat_losses.step(crucible.pos, direction)?;
+                let run = match crucible.direction {
+                    Some(d) if d == direction => crucible.run + 1,
+                    _ => 1,
+                };
+                let next = Crucible { pos, direction: Some(direction), run };
+                Some((next, self.heat_losses[pos]))
+            })
+            .collect()
+    }
+
+    /// Least heat lost moving a crucible from the top left to the bottom right,
+    /// if it has to move min_run to max_run blocks in a straight line,
+    /// including before it stops at the bottom right.
+    fn least_heat_loss(&self, runs: (usize, usize)) -> Option<u32> {
+        let goal =
+            (self.heat_losses.width() - 1, self.heat_losses.height() - 1);
+        let start = Crucible { pos: (0, 0), direction: None, run: 0 };
+        let (heat_loss, _path) = search::dijkstra(
+            start,
+            |&crucible| self.moves(crucible, runs),
+            |crucible| crucible.pos == goal && crucible.run >= runs.0,
+        )?;
+        Some(heat_loss)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_least_heat_loss() {
+        let lines = ["1199", "9119", "9911"].map(|s| s.to_string());
+        let city = City::new(&lines).unwrap();
+        // Along the staircase of ones
+        assert_eq!(city.least_heat_loss((1, 3)), Some(5));
+        // Has to turn after every block, which the staircase does
+        assert_eq!(city.least_heat_loss((1, 1)), Some(5));
+        // Moves right 3 blocks and then down 2, or down 2 and then right 3
+        assert_eq!(city.least_heat_loss((2, 3)), Some(1 + 9 + 9 + 9 + 1));
+        // Cannot move 3 blocks down
+        assert_eq!(city.least_heat_loss((3, 3)), None);
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = City;
+
+    fn parse(lines: Vec<String>) -> Result<City> {
+        City::new(&lines)
+    }
+
+    /// Part 1: A crucible moves at most 3 blocks before it turns
+    fn part1(city: &City) -> Result<Answer> {
+        let heat_loss = city
+            .least_heat_loss((1, 3))
+            .context("could not reach the bottom right")?;
+        Ok(heat_loss.into())
+    }
+
+    /// Part 2: An ultra crucible moves 4 to 10 blocks before it turns
+    fn part2(city: &City) -> Result<Answer> {
+        let heat_loss = city
+            .least_heat_loss((4, 10))
+            .context("could not reach the bottom right")?;
+        Ok(heat_loss.into())
+    }
+}
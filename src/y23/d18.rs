@@ -0,0 +1,144 @@
+use anyhow::{bail, Context, Result};
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::geom::{self, Direction, Point};
+use crate::util::solution::Solution;
+
+/// Digging some meters towards a direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Dig {
+    direction: Direction,
+    meters: i64,
+}
+
+impl Dig {
+    /// Parses a line of the dig plan, which is read two ways:
+    /// as written, and with the distance and direction hidden in the color.
+    fn new(input: &str) -> Result<(Dig, Dig)> {
+        let (direction, meters, color) =
+            sscanf!(input, "{} {} (#{})", char, i64, str)
+                .ok()
+                .with_context(|| format!("invalid dig `{}`", input))?;
+        let direction = match direction {
+            'U' => Direction::North,
+            'R' => Direction::East,
+            'D' => Direction::South,
+            'L' => Direction::West,
+            _ => bail!("invalid direction `{}`", direction),
+        };
+        if color.len() != 6 {
+            bail!("expected 6 hexadecimal digits in `{}`", color);
+        }
+        let hidden_meters = i64::from_str_radix(&color[..5], 16)
+            .with_context(|| format!("invalid distance in `{}`", color))?;
+        let hidden_direction = match &color[5..] {
+            "0" => Direction::East,
+            "1" => Direction::South,
+            "2" => Direction::West,
+            "3" => Direction::North,
+            _ => bail!("invalid direction in `{}`", color),
+        };
+        Ok((
+            Dig { direction, meters },
+            Dig { direction: hidden_direction, meters: hidden_meters },
+        ))
+    }
+}
+
+/// Cubic meters of lava that the lagoon holds,
+/// counting both the trench and the interior that is dug out.
+/// The trench is a polygon through the centers of the cubes dug,
+/// so its cubes are the points on its boundary.
+fn lagoon_size(plan: &[Dig]) -> Result<i64> {
+    let mut vertices = vec![];
+    let mut pos = Point::ORIGIN;
+    for dig in plan {
+        vertices.push(pos);
+        pos += dig.direction.delta() * dig.meters;
+    }
+    if pos != Point::ORIGIN {
+        bail!("trench does not loop back to the start");
+    }
+    Ok(geom::interior_points(&vertices) + geom::boundary_points(&vertices))
+}
+
+pub struct DigPlan {
+    /// The plan as written
+    written: Vec<Dig>,
+    /// The plan as hidden in the colors
+    hidden: Vec<Dig>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dig() {
+        let (written, hidden) = Dig::new("L 4 (#0dc571)").unwrap();
+        assert_eq!(written, Dig { direction: Direction::West, meters: 4 });
+        assert_eq!(hidden, Dig { direction: Direction::South, meters: 56407 });
+        assert!(Dig::new("L 4 (#0dc574)").is_err());
+    }
+
+    #[test]
+    fn test_lagoon_size() {
+        let plan = |digs: &[(Direction, i64)]| {
+            digs.iter()
+                .map(|&(direction, meters)| Dig { direction, meters })
+                .collect::<Vec<_>>()
+        };
+        // A 4x3 trench around two cubes
+        let rectangle = plan(&[
+            (Direction::East, 3),
+            (Direction::South, 2),
+            (Direction::West, 3),
+            (Direction::North, 2),
+        ]);
+        assert_eq!(lagoon_size(&rectangle).unwrap(), 4 * 3);
+        assert!(lagoon_size(&rectangle[1..]).is_err());
+        // A ring of 12 cubes in steps, which encloses no other cube
+        let staircase = plan(&[
+            (Direction::East, 1),
+            (Direction::South, 1),
+            (Direction::East, 1),
+            (Direction::South, 1),
+            (Direction::West, 1),
+            (Direction::South, 1),
+            (Direction::West, 1),
+            (Direction::North, 1),
+            (Direction::West, 1),
+            (Direction::North, 1),
+            (Direction::East, 1),
+            (Direction::North, 1),
+        ]);
+        assert_eq!(lagoon_size(&staircase).unwrap(), 12);
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+
+    fn parse(lines: Vec<String>) -> Result<DigPlan> {
+        let (written, hidden) = lines
+            .iter()
+            .map(|line| Dig::new(line))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        Ok(DigPlan { written, hidden })
+    }
+
+    /// Part 1: Follow the plan as written
+    fn part1(plan: &DigPlan) -> Result<Answer> {
+        Ok(lagoon_size(&plan.written)?.into())
+    }
+
+    /// Part 2: Follow the plan hidden in the colors
+    fn part2(plan: &DigPlan) -> Result<Answer> {
+        Ok(lagoon_size(&plan.hidden)?.into())
+    }
+}
@@ -5,6 +5,7 @@ use std::{
     str::FromStr,
 };
 
+use crate::util::interval::Interval;
 use crate::util::vectors::group;
 
 #[derive(PartialEq, Eq, Debug, FromScanf, Clone, Copy)]
@@ -28,44 +29,24 @@ struct Part {
     s: u32,
 }
 
-/// Range is left-inclusive i.e., Range(1,4001) means "numbers from 1 to 4000"
-#[derive(PartialEq, Eq, Debug, Clone, Copy)]
-struct Range(u32, u32);
-
-impl Range {
-    fn empty() -> Range {
-        Range(0, 0)
-    }
-
-    fn is_empty(&self) -> bool {
-        *self == Range::empty()
-    }
-
-    /// Splits a range in the middle with v.
-    fn split(&self, v: u32) -> (Range, Range) {
-        if v < self.0 {
-            (Range::empty(), *self)
-        } else if v >= self.1 {
-            (*self, Range::empty())
-        } else {
-            (Range(self.0, v), Range(v, self.1))
-        }
-    }
-
-    fn count(&self) -> u32 {
-        self.1 - self.0
-    }
-}
-
 #[derive(PartialEq, Eq, Clone, Debug)]
 struct PartRange {
-    x: Range,
-    m: Range,
-    a: Range,
-    s: Range,
+    x: Interval<u32>,
+    m: Interval<u32>,
+    a: Interval<u32>,
+    s: Interval<u32>,
 }
 
 impl PartRange {
+    fn get_mut(&mut self, category: Category) -> &mut Interval<u32> {
+        match category {
+            Category::X => &mut self.x,
+            Category::M => &mut self.m,
+            Category::A => &mut self.a,
+            Category::S => &mut self.s,
+        }
+    }
+
     /// Splits PartRange via Category at v.
     /// The first value contains PartRange where at a given `category` is less than `v`,
     /// whereas the second value contains PartRange where at a given `category` is greater than or equal to `v`.
@@ -74,84 +55,25 @@ impl PartRange {
         category: Category,
         v: u32,
     ) -> (Option<PartRange>, Option<PartRange>) {
-        let range_to_split = match category {
-            Category::X => self.x,
-            Category::M => self.m,
-            Category::A => self.a,
-            Category::S => self.s,
-        };
-        let (left_range, right_range) = range_to_split.split(v);
-        if left_range.is_empty() {
-            (None, Some(self.clone()))
-        } else if right_range.is_empty() {
-            (Some(self.clone()), None)
-        } else {
-            match category {
-                Category::X => (
-                    Some(PartRange {
-                        x: left_range,
-                        m: self.m,
-                        a: self.a,
-                        s: self.s,
-                    }),
-                    Some(PartRange {
-                        x: right_range,
-                        m: self.m,
-                        a: self.a,
-                        s: self.s,
-                    }),
-                ),
-                Category::M => (
-                    Some(PartRange {
-                        x: self.x,
-                        m: left_range,
-                        a: self.a,
-                        s: self.s,
-                    }),
-                    Some(PartRange {
-                        x: self.x,
-                        m: right_range,
-                        a: self.a,
-                        s: self.s,
-                    }),
-                ),
-                Category::A => (
-                    Some(PartRange {
-                        x: self.x,
-                        m: self.m,
-                        a: left_range,
-                        s: self.s,
-                    }),
-                    Some(PartRange {
-                        x: self.x,
-                        m: self.m,
-                        a: right_range,
-                        s: self.s,
-                    }),
-                ),
-                Category::S => (
-                    Some(PartRange {
-                        x: self.x,
-                        m: self.m,
-                        a: self.a,
-                        s: left_range,
-                    }),
-                    Some(PartRange {
-                        x: self.x,
-                        m: self.m,
-                        a: self.a,
-                        s: right_range,
-                    }),
-                ),
+        let (mut left, mut right) = (self.clone(), self.clone());
+        let (left_range, right_range) = left.get_mut(category).split_at(v);
+        *left.get_mut(category) = left_range;
+        *right.get_mut(category) = right_range;
+        let non_empty = |part_range: PartRange, range: Interval<u32>| {
+            if range.is_empty() {
+                None
+            } else {
+                Some(part_range)
             }
-        }
+        };
+        (non_empty(left, left_range), non_empty(right, right_range))
     }
 
     fn count(&self) -> u64 {
-        self.x.count() as u64
-            * self.m.count() as u64
-            * self.a.count() as u64
-            * self.s.count() as u64
+        self.x.len() as u64
+            * self.m.len() as u64
+            * self.a.len() as u64
+            * self.s.len() as u64
     }
 }
 
@@ -423,10 +345,10 @@ fn solve_part_1(system: &System, parts: &Vec<Part>) -> anyhow::Result<String> {
 
 fn solve_part_2(system: &System) -> anyhow::Result<String> {
     let result = system.count_dest_range(&PartRange {
-        x: Range(1, 4001),
-        m: Range(1, 4001),
-        a: Range(1, 4001),
-        s: Range(1, 4001),
+        x: Interval::from_inclusive(1, 4000),
+        m: Interval::from_inclusive(1, 4000),
+        a: Interval::from_inclusive(1, 4000),
+        s: Interval::from_inclusive(1, 4000),
     })?;
     Ok(result.to_string())
 }
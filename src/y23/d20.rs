@@ -0,0 +1,280 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{bail, Context, Result};
+use num::Integer;
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+const BROADCASTER: &str = "broadcaster";
+const RX: &str = "rx";
+/// Button pushes to try before giving up on waiting for a pulse
+const MAX_PUSHES: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Broadcaster,
+    /// Flips on a low pulse, and ignores high pulses
+    FlipFlop,
+    /// Remembers the last pulse from each input
+    Conjunction,
+    /// Only receives pulses, e.g., `rx`
+    Untyped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Module {
+    kind: Kind,
+    inputs: Vec<usize>,
+    outputs: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pulse {
+    from: usize,
+    to: usize,
+    high: bool,
+}
+
+/// What the modules remember between pulses
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct State {
+    /// Whether each flip-flop is on
+    on: Vec<bool>,
+    /// Last pulse that each conjunction received from each of its inputs,
+    /// in the same order as its inputs
+    memory: Vec<Vec<bool>>,
+}
+
+pub struct Network {
+    names: Vec<String>,
+    modules: Vec<Module>,
+    broadcaster: usize,
+}
+
+impl Network {
+    fn new(lines: &[String]) -> Result<Network> {
+        let mut definitions = vec![];
+        for line in lines {
+            let (name, outputs) = line
+                .split_once(" -> ")
+                .with_context(|| format!("invalid module `{}`", line))?;
+            let (kind, name) =
+                match (name.strip_prefix('%'), name.strip_prefix('&')) {
+                    (Some(name), _) => (Kind::FlipFlop, name),
+                    (_, Some(name)) => (Kind::Conjunction, name),
+                    _ if name == BROADCASTER => (Kind::Broadcaster, name),
+                    _ => bail!("invalid module `{}`", name),
+                };
+            definitions.push((
+                kind,
+                name,
+                outputs.split(", ").collect::<Vec<_>>(),
+            ));
+        }
+
+        let mut names = vec![];
+        let mut indices = HashMap::new();
+        let mut index = |name: &str| {
+            *indices.entry(name.to_string()).or_insert_with(|| {
+                names.push(name.to_string());
+                names.len() - 1
+            })
+        };
+        // Defined modules come first, so that they are numbered in order
+        let defined = definitions
+            .iter()
+            .map(|(_, name, _)| index(name))
+            .collect::<Vec<_>>();
+        if defined.iter().enumerate().any(|(ii, index)| ii != *index) {
+            bail!("a module is defined twice");
+        }
+        let outputs = definitions
+            .iter()
+            .map(|(_, _, outputs)| {
+                outputs.iter().map(|name| index(name)).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut modules =
+            vec![
+                Module { kind: Kind::Untyped, inputs: vec![], outputs: vec![] };
+                names.len()
+            ];
+        for (ii, ((kind, _, _), outputs)) in
+            definitions.iter().zip(outputs).enumerate()
+        {
+            for output in &outputs {
+                modules[*output].inputs.push(ii);
+            }
+            modules[ii].kind = *kind;
+            modules[ii].outputs = outputs;
+        }
+        let broadcaster = names
+            .iter()
+            .position(|name| name == BROADCASTER)
+            .context("there is no broadcaster")?;
+        Ok(Network { names, modules, broadcaster })
+    }
+
+    fn module(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    fn initial_state(&self) -> State {
+        State {
+            on: vec![false; self.modules.len()],
+            memory: self
+                .modules
+                .iter()
+                .map(|module| vec![false; module.inputs.len()])
+                .collect(),
+        }
+    }
+
+    /// Pushes the button once, which sends a low pulse to the broadcaster,
+    /// and handles pulses in the order they are sent until there are none left.
+    /// Calls observe on every pulse, including the button's.
+    fn push_button<F>(&self, state: &mut State, mut observe: F)
+    where
+        F: FnMut(Pulse),
+    {
+        // The button is not a module, so it is said to be the broadcaster
+        let button =
+            Pulse { from: self.broadcaster, to: self.broadcaster, high: false };
+        let mut queue = VecDeque::from([button]);
+        while let Some(pulse) = queue.pop_front() {
+            observe(pulse);
+            let module = &self.modules[pulse.to];
+            let high = match module.kind {
+                Kind::Broadcaster => pulse.high,
+                Kind::FlipFlop if pulse.high => continue,
+                Kind::FlipFlop => {
+                    state.on[pulse.to] = !state.on[pulse.to];
+                    state.on[pulse.to]
+                }
+                Kind::Conjunction => {
+                    let memory = &mut state.memory[pulse.to];
+                    let input = module
+                        .inputs
+                        .iter()
+                        .position(|i| *i == pulse.from)
+                        .unwrap();
+                    memory[input] = pulse.high;
+                    !memory.iter().all(|high| *high)
+                }
+                Kind::Untyped => continue,
+            };
+            for &to in &module.outputs {
+                queue.push_back(Pulse { from: pulse.to, to, high });
+            }
+        }
+    }
+
+    /// Number of low pulses and high pulses sent over some button pushes
+    fn count_pulses(&self, pushes: usize) -> (usize, usize) {
+        let mut state = self.initial_state();
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..pushes {
+            self.push_button(&mut state, |pulse| match pulse.high {
+                false => low += 1,
+                true => high += 1,
+            });
+        }
+        (low, high)
+    }
+
+    /// Button pushes until each input of a conjunction first sends it a high pulse
+    fn first_high_pushes(&self, conjunction: usize) -> Result<Vec<usize>> {
+        let inputs = &self.modules[conjunction].inputs;
+        let mut pushes = vec![None; inputs.len()];
+        let mut state = self.initial_state();
+        for push in 1..=MAX_PUSHES {
+            self.push_button(&mut state, |pulse| {
+                if pulse.to == conjunction && pulse.high {
+                    let input = inputs.iter().position(|i| *i == pulse.from);
+                    pushes[input.unwrap()].get_or_insert(push);
+                }
+            });
+            if pushes.iter().all(|p| p.is_some()) {
+                return Ok(pushes.into_iter().flatten().collect());
+            }
+        }
+        bail!("inputs of {} did not send high pulses", self.names[conjunction])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network(lines: &[&str]) -> Network {
+        let lines = lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        Network::new(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_count_pulses() {
+        let network = network(&[
+            "broadcaster -> a",
+            "%a -> inv, con",
+            "&inv -> b",
+            "%b -> con",
+            "&con -> output",
+        ]);
+        assert_eq!(network.count_pulses(1), (4, 4));
+        assert_eq!(network.count_pulses(2), (4 + 4, 4 + 2));
+        assert_eq!(network.count_pulses(4), (17, 11));
+    }
+
+    #[test]
+    fn test_first_high_pushes() {
+        let network = network(&[
+            "broadcaster -> a, b",
+            "%a -> hub",
+            "%b -> c",
+            "%c -> hub",
+            "&hub -> rx",
+        ]);
+        // a turns on with the first push,
+        // and c turns on once b turns off with the second push
+        let hub = network.module("hub").unwrap();
+        assert_eq!(network.first_high_pushes(hub).unwrap(), vec![1, 2]);
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Network;
+
+    fn parse(lines: Vec<String>) -> Result<Network> {
+        Network::new(&lines)
+    }
+
+    /// Part 1: Count pulses over 1000 button pushes
+    fn part1(network: &Network) -> Result<Answer> {
+        let (low, high) = network.count_pulses(1000);
+        Ok((low * high).into())
+    }
+
+    /// Part 2: Fewest button pushes until rx receives a low pulse.
+    /// rx only hears from one conjunction, which sends a low pulse once all its inputs
+    /// last sent it a high pulse. Each input is expected to send a high pulse
+    /// on a cycle that starts from the first push, so they line up at their LCM.
+    fn part2(network: &Network) -> Result<Answer> {
+        let rx = network.module(RX).context("there is no rx")?;
+        let conjunction = match network.modules[rx].inputs[..] {
+            [input] if network.modules[input].kind == Kind::Conjunction => {
+                input
+            }
+            _ => bail!("expected rx to hear from a single conjunction"),
+        };
+        let pushes = network
+            .first_high_pushes(conjunction)?
+            .into_iter()
+            .reduce(|p1, p2| p1.lcm(&p2))
+            .context("the conjunction has no inputs")?;
+        Ok(pushes.into())
+    }
+}
@@ -1,7 +1,8 @@
 use anyhow::{bail, Context, Ok, Result};
-use std::collections::VecDeque;
 
-use crate::util::vectors::Grid;
+use crate::util::geom::Point;
+use crate::util::grid::Grid;
+use crate::util::search;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Cell {
@@ -15,83 +16,40 @@ enum Cell {
 
 #[derive(Debug)]
 struct Map {
-    cells: Vec<Vec<Cell>>,
-    width: usize,
-    height: usize,
+    cells: Grid<Cell>,
+    start: (usize, usize),
 }
 
 impl Map {
-    fn new(grid: Grid) -> Result<Map> {
-        let mut cells = grid
-            .chars
-            .iter()
-            .map(|row| {
-                row.iter()
-                    .map(|c| match c {
-                        'S' => Some(Cell::Reachable(0)),
-                        '#' => Some(Cell::Rock),
-                        '.' => Some(Cell::Unreachable),
-                        _ => None,
-                    })
-                    .collect::<Option<Vec<_>>>()
-            })
-            .collect::<Option<Vec<_>>>()
-            .context("cannot parse all characters")?;
-
-        let (width, height) = (grid.width, grid.height);
-        let next_positions = |(pos_x, pos_y): (usize, usize)| {
-            let mut result = vec![];
-            if pos_x >= 1 {
-                result.push((pos_x - 1, pos_y));
-            }
-            if pos_x + 1 < width {
-                result.push((pos_x + 1, pos_y));
-            }
-            if pos_y >= 1 {
-                result.push((pos_x, pos_y - 1));
-            }
-            if pos_y + 1 < height {
-                result.push((pos_x, pos_y + 1));
-            }
-            result
-        };
-
-        // Look for the starting position
-        let mut starting_position = None;
-        'l: for pos_x in 0..width {
-            for pos_y in 0..height {
-                if cells[pos_y][pos_x] == Cell::Reachable(0) {
-                    starting_position = Some((pos_x, pos_y));
-                    break 'l;
-                }
-            }
-        }
-        if starting_position == None {
-            bail!("cannot find starting position")
-        }
-        let starting_position = starting_position.unwrap();
+    fn new(lines: &[String]) -> Result<Map> {
+        let mut cells = Grid::parse_with(lines, |c| match c {
+            'S' => Some(Cell::Reachable(0)),
+            '#' => Some(Cell::Rock),
+            '.' => Some(Cell::Unreachable),
+            _ => None,
+        })
+        .context("cannot parse all characters")?;
+
+        let starting_position = cells
+            .find(|cell| *cell == Cell::Reachable(0))
+            .context("cannot find starting position")?;
 
         // Then look for Reachables
-        let mut queue = VecDeque::new();
-        queue.push_back((starting_position, 0));
-        while let Some(((pos_x, pos_y), cur_count)) = queue.pop_front() {
-            if cur_count != 0 && cells[pos_y][pos_x] != Cell::Unreachable {
-                continue;
-            }
-            cells[pos_y][pos_x] = Cell::Reachable(cur_count);
-            for (next_pos_x, next_pos_y) in next_positions((pos_x, pos_y)) {
-                if cells[next_pos_y][next_pos_x] != Cell::Unreachable {
-                    continue;
-                }
-                queue.push_back(((next_pos_x, next_pos_y), cur_count + 1));
-            }
+        let distances = search::bfs_all_distances(starting_position, |&pos| {
+            cells
+                .neighbors4(pos)
+                .filter(|&next_pos| cells[next_pos] != Cell::Rock)
+                .collect::<Vec<_>>()
+        });
+        for (pos, distance) in distances {
+            cells[pos] = Cell::Reachable(distance as u32);
         }
 
-        Ok(Map { cells, width, height })
+        Ok(Map { cells, start: starting_position })
     }
 
-    fn at(&self, (pos_x, pos_y): (usize, usize)) -> Cell {
-        self.cells[pos_y][pos_x]
+    fn at(&self, pos: (usize, usize)) -> Cell {
+        self.cells[pos]
     }
 
     /// Counts how many steps are reachable given the parameter
@@ -99,20 +57,78 @@ impl Map {
         // The space must match the parity of `steps`; i.e., if steps is odd, then the space must be odd.
         // In addition, it should be at most `steps`.
         let mut result = 0;
-        for pos_x in 0..self.width {
-            for pos_y in 0..self.height {
-                match self.at((pos_x, pos_y)) {
-                    Cell::Reachable(c) => {
-                        if c <= steps && (c + steps) % 2 == 0 {
-                            result += 1;
-                        }
+        for pos in self.cells.positions() {
+            match self.at(pos) {
+                Cell::Reachable(c) => {
+                    if c <= steps && (c + steps) % 2 == 0 {
+                        result += 1;
                     }
-                    _ => {}
                 }
+                _ => {}
             }
         }
         result
     }
+
+    /// Whether there is a rock at pos, where the map repeats infinitely in every direction
+    fn is_rock_infinite(&self, pos: Point) -> bool {
+        let (width, height) =
+            (self.cells.width() as i64, self.cells.height() as i64);
+        let wrapped = (
+            pos.x.rem_euclid(width) as usize,
+            pos.y.rem_euclid(height) as usize,
+        );
+        self.at(wrapped) == Cell::Rock
+    }
+
+    /// Counts how many steps are reachable given the parameter,
+    /// where the map repeats infinitely in every direction
+    fn count_reachable_at_infinite(&self, steps: u32) -> u64 {
+        let start = Point::from_grid_pos(self.start);
+        // Plots further than `steps` from start can't be reached anyway
+        let distances = search::bfs_all_distances(start, |pos| {
+            pos.neighbors4()
+                .into_iter()
+                .filter(|next| {
+                    next.manhattan(start) <= steps as i64
+                        && !self.is_rock_infinite(*next)
+                })
+                .collect::<Vec<_>>()
+        });
+        distances
+            .values()
+            .filter(|&&c| c <= steps as usize && c % 2 == steps as usize % 2)
+            .count() as u64
+    }
+
+    /// Counts how many steps are reachable given the parameter,
+    /// where the map repeats infinitely in every direction.
+    /// Only works for square maps whose starting position is at the center,
+    /// and where steps ends at the edge of a map, as in the puzzle input.
+    ///
+    /// Since the row and column of the starting position are free of rocks,
+    /// the reachable plots form a diamond that grows by one map in each direction
+    /// every `width` steps, so the count is quadratic in the number of maps.
+    /// The quadratic is found by counting the first three diamonds directly.
+    fn extrapolate_reachable_at(&self, steps: u32) -> Result<u64> {
+        let size = self.cells.width() as u32;
+        if self.cells.height() as u32 != size
+            || self.start != (size as usize / 2, size as usize / 2)
+        {
+            bail!("starting position is not at the center of a square map");
+        }
+        if steps % size != size / 2 {
+            bail!("{} steps do not end at the edge of a map", steps);
+        }
+
+        let [a0, a1, a2] = [0, 1, 2]
+            .map(|n| self.count_reachable_at_infinite(size / 2 + n * size));
+        let n = (steps / size) as u64;
+        // Newton's forward differences; a2 - 2 * a1 + a0 is never negative
+        let first = a1 - a0;
+        let second = a2 + a0 - 2 * a1;
+        Ok(a0 + n * first + n * n.saturating_sub(1) / 2 * second)
+    }
 }
 
 #[cfg(test)]
@@ -132,13 +148,7 @@ mod tests {
 ...........";
     fn example_map() -> Map {
         Map::new(
-            Grid::new(
-                &EXAMPLE_MAP
-                    .split('\n')
-                    .map(|s| s.to_string())
-                    .collect::<Vec<_>>(),
-            )
-            .unwrap(),
+            &EXAMPLE_MAP.split('\n').map(|s| s.to_string()).collect::<Vec<_>>(),
         )
         .unwrap()
     }
@@ -151,6 +161,16 @@ mod tests {
         assert_eq!(map.count_reachable_at(3), 6);
         assert_eq!(map.count_reachable_at(6), 16);
     }
+
+    #[test]
+    fn test_count_reachable_at_infinite() {
+        let map = example_map();
+        assert_eq!(map.count_reachable_at_infinite(6), 16);
+        assert_eq!(map.count_reachable_at_infinite(10), 50);
+        assert_eq!(map.count_reachable_at_infinite(50), 1594);
+        assert_eq!(map.count_reachable_at_infinite(100), 6536);
+        assert_eq!(map.count_reachable_at_infinite(500), 167004);
+    }
 }
 
 fn solve_part_1(map: &Map) -> Result<String> {
@@ -158,22 +178,10 @@ fn solve_part_1(map: &Map) -> Result<String> {
 }
 
 fn solve_part_2(map: &Map) -> Result<String> {
-    for pos_y in 0..map.height {
-        for pos_x in 0..map.width {
-            let s = match map.at((pos_x, pos_y)) {
-                Cell::Reachable(v) => format!("{:02}", v),
-                Cell::Rock => "##".to_string(),
-                Cell::Unreachable => "!!".to_string(),
-            };
-            print!("{} ", s);
-        }
-        println!()
-    }
-    bail!("bruh")
+    Ok(map.extrapolate_reachable_at(26501365)?.to_string())
 }
 
 pub fn solve(lines: Vec<String>) -> Result<(Result<String>, Result<String>)> {
-    let grid = Grid::new(&lines).context("cannot create grid from input")?;
-    let map = Map::new(grid).context("cannot create map from grid")?;
+    let map = Map::new(&lines).context("cannot create map from input")?;
     Ok((solve_part_1(&map), solve_part_2(&map)))
 }
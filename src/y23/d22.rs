@@ -0,0 +1,158 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use sscanf::sscanf;
+
+use crate::util::answer::Answer;
+use crate::util::geom::Point3;
+use crate::util::solution::Solution;
+
+/// A brick spanning the cubes from one corner to the other, inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Brick {
+    low: Point3,
+    high: Point3,
+}
+
+impl Brick {
+    fn new(input: &str) -> Result<Brick> {
+        let corner = |input: &str| {
+            let (x, y, z) = sscanf!(input, "{},{},{}", i64, i64, i64).ok()?;
+            Some(Point3::new(x, y, z))
+        };
+        let (a, b) = sscanf!(input, "{}~{}", str, str)
+            .ok()
+            .and_then(|(a, b)| Some((corner(a)?, corner(b)?)))
+            .with_context(|| format!("invalid brick `{}`", input))?;
+        let brick = Brick {
+            low: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            high: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        };
+        if brick.low.z < 1 {
+            bail!("brick `{}` is in the ground", input);
+        }
+        Ok(brick)
+    }
+
+    /// Positions of the brick as seen from above
+    fn footprint(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        (self.low.x..=self.high.x)
+            .flat_map(move |x| (self.low.y..=self.high.y).map(move |y| (x, y)))
+    }
+}
+
+/// Which bricks rest on which, after every brick has fallen
+pub struct Supports {
+    /// Bricks that each brick rests on
+    below: Vec<Vec<usize>>,
+    /// Bricks that rest on each brick
+    above: Vec<Vec<usize>>,
+}
+
+impl Supports {
+    /// Lets the bricks fall, from the lowest one upwards
+    fn new(bricks: &[Brick]) -> Supports {
+        let mut order = (0..bricks.len()).collect::<Vec<_>>();
+        order.sort_by_key(|ii| bricks[*ii].low.z);
+        // Top of the tallest brick at each position, and which brick it is
+        let mut tops: HashMap<(i64, i64), (i64, usize)> = HashMap::new();
+        let mut below = vec![vec![]; bricks.len()];
+        let mut above = vec![vec![]; bricks.len()];
+        for ii in order {
+            let brick = &bricks[ii];
+            let under = brick
+                .footprint()
+                .filter_map(|pos| tops.get(&pos).copied())
+                .collect::<Vec<_>>();
+            let floor = under.iter().map(|(top, _)| *top).max().unwrap_or(0);
+            let supporters = under
+                .iter()
+                .filter(|(top, _)| *top == floor)
+                .map(|(_, jj)| *jj)
+                .sorted()
+                .dedup();
+            for jj in supporters {
+                below[ii].push(jj);
+                above[jj].push(ii);
+            }
+            let top = floor + 1 + brick.high.z - brick.low.z;
+            for pos in brick.footprint() {
+                tops.insert(pos, (top, ii));
+            }
+        }
+        Supports { below, above }
+    }
+
+    /// Whether a brick can be taken out without any other brick falling
+    fn is_safe(&self, brick: usize) -> bool {
+        self.above[brick].iter().all(|other| self.below[*other].len() > 1)
+    }
+
+    /// Number of other bricks that fall if a brick is taken out
+    fn chain_reaction(&self, brick: usize) -> usize {
+        let mut fallen = HashSet::from([brick]);
+        let mut stack = vec![brick];
+        while let Some(current) = stack.pop() {
+            for &other in &self.above[current] {
+                if !fallen.contains(&other)
+                    && self.below[other].iter().all(|b| fallen.contains(b))
+                {
+                    fallen.insert(other);
+                    stack.push(other);
+                }
+            }
+        }
+        fallen.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supports() {
+        // A bridge on two pillars, with a brick on top of its middle
+        let bricks =
+            ["0,0,1~0,0,2", "2,0,1~2,0,2", "0,0,5~2,0,5", "1,0,9~1,0,9"]
+                .map(|s| Brick::new(s).unwrap());
+        let supports = Supports::new(&bricks);
+        assert_eq!(supports.below, vec![vec![], vec![], vec![0, 1], vec![2]]);
+        assert!(supports.is_safe(0));
+        assert!(!supports.is_safe(2));
+        assert!(supports.is_safe(3));
+        assert_eq!(supports.chain_reaction(0), 0);
+        assert_eq!(supports.chain_reaction(2), 1);
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Supports;
+
+    fn parse(lines: Vec<String>) -> Result<Supports> {
+        let bricks = lines
+            .iter()
+            .map(|line| Brick::new(line))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Supports::new(&bricks))
+    }
+
+    /// Part 1: Count the bricks that can be safely disintegrated
+    fn part1(supports: &Supports) -> Result<Answer> {
+        let count = (0..supports.below.len())
+            .filter(|brick| supports.is_safe(*brick))
+            .count();
+        Ok(count.into())
+    }
+
+    /// Part 2: Sum how many bricks fall when each brick is disintegrated
+    fn part2(supports: &Supports) -> Result<Answer> {
+        let sum = (0..supports.below.len())
+            .map(|brick| supports.chain_reaction(brick))
+            .sum::<usize>();
+        Ok(sum.into())
+    }
+}
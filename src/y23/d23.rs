@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+
+use crate::util::answer::Answer;
+use crate::util::geom::Direction;
+use crate::util::grid::Grid;
+use crate::util::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Path,
+    Forest,
+    /// Can only be stepped off of downhill
+    Slope(Direction),
+}
+
+/// Junctions of the trails, and the longest trail between each pair of them
+/// that passes no other junction
+struct Junctions {
+    /// Trails leaving each junction, by the junction they reach and their length
+    trails: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+pub struct Map {
+    tiles: Grid<Tile>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Map {
+    fn new(lines: &[String]) -> Result<Map> {
+        let tiles = Grid::parse_with(lines, |c| match c {
+            '.' => Some(Tile::Path),
+            '#' => Some(Tile::Forest),
+            '^' => Some(Tile::Slope(Direction::North)),
+            '>' => Some(Tile::Slope(Direction::East)),
+            'v' => Some(Tile::Slope(Direction::South)),
+            '<' => Some(Tile::Slope(Direction::West)),
+            _ => None,
+        })?;
+        let opening = |y: usize| {
+            (0..tiles.width())
+                .find(|x| tiles[(*x, y)] == Tile::Path)
+                .map(|x| (x, y))
+                .with_context(|| format!("there is no path in row {}", y))
+        };
+        let (start, end) = (opening(0)?, opening(tiles.height() - 1)?);
+        if start == end {
+            bail!("the map is too small");
+        }
+        Ok(Map { tiles, start, end })
+    }
+
+    /// Where one can step from pos.
+    /// If slopes are slippery, one can only step downhill from or onto them.
+    fn steps(
+        &self,
+        pos: (usize, usize),
+        slippery: bool,
+    ) -> Vec<(usize, usize)> {
+        let directions = match self.tiles[pos] {
+            Tile::Slope(direction) if slippery => vec![direction],
+            _ => Direction::ALL.to_vec(),
+        };
+        directions
+            .into_iter()
+            .filter_map(|direction| {
+                let next = self.tiles.step(pos, direction)?;
+                match self.tiles[next] {
+                    Tile::Forest => None,
+                    Tile::Slope(slope)
+                        if slippery && slope == direction.rev() =>
+                    {
+                        None
+                    }
+                    _ => Some(next),
+                }
+            })
+            .collect()
+    }
+
+    /// Contracts the map into the junctions, where trails meet,
+    /// along with the start and the end.
+    fn junctions(&self, slippery: bool) -> Junctions {
+        let is_junction = |pos: (usize, usize)| {
+            pos == self.start
+                || pos == self.end
+                || (self.tiles[pos] != Tile::Forest
+                    && self
+                        .tiles
+                        .neighbors4(pos)
+                        .filter(|next| self.tiles[*next] != Tile::Forest)
+                        .count()
+                        >= 3)
+        };
+        let positions = self
+            .tiles
+            .positions()
+            .filter(|pos| is_junction(*pos))
+            .collect::<Vec<_>>();
+        let indices = positions
+            .iter()
+            .enumerate()
+            .map(|(ii, pos)| (*pos, ii))
+            .collect::<HashMap<_, _>>();
+
+        let mut trails = vec![vec![]; positions.len()];
+        for (ii, &junction) in positions.iter().enumerate() {
+            // Follows each trail out of the junction until the next one
+            for first in self.steps(junction, slippery) {
+                let (mut previous, mut pos, mut length) = (junction, first, 1);
+                while !indices.contains_key(&pos) {
+                    let next = self
+                        .steps(pos, slippery)
+                        .into_iter()
+                        .find(|next| *next != previous);
+                    let Some(next) = next else { break };
+                    (previous, pos, length) = (pos, next, length + 1);
+                }
+                if let Some(&jj) = indices.get(&pos) {
+                    trails[ii].push((jj, length));
+                }
+            }
+        }
+        Junctions {
+            trails,
+            start: indices[&self.start],
+            end: indices[&self.end],
+        }
+    }
+}
+
+impl Junctions {
+    /// Length of the longest hike from the start to the end
+    /// that does not visit any junction twice
+    fn longest_hike(&self) -> Option<usize> {
+        fn explore(
+            junctions: &Junctions,
+            current: usize,
+            visited: &mut Vec<bool>,
+        ) -> Option<usize> {
+            if current == junctions.end {
+                return Some(0);
+            }
+            visited[current] = true;
+            let mut longest = None;
+            for &(next, length) in &junctions.trails[current] {
+                if visited[next] {
+                    continue;
+                }
+                if let Some(rest) = explore(junctions, next, visited) {
+                    longest = longest.max(Some(length + rest));
+                }
+            }
+            visited[current] = false;
+            longest
+        }
+        explore(self, self.start, &mut vec![false; self.trails.len()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_hike() {
+        // Two ways around a block of forest, which are as long as each other
+        let lines = [
+            "#.#####", "#.....#", "#.###.#", "#v###v#", "#.....#", "#####.#",
+            "#####.#",
+        ]
+        .map(|s| s.to_string());
+        let map = Map::new(&lines).unwrap();
+        let junctions = map.junctions(true);
+        assert_eq!(junctions.trails.len(), 4);
+        assert_eq!(junctions.longest_hike(), Some(1 + 7 + 2));
+        assert_eq!(map.junctions(false).longest_hike(), Some(1 + 7 + 2));
+        // The slope cannot be climbed
+        let lines = ["#.#", "#^#", "#.#"].map(|s| s.to_string());
+        let map = Map::new(&lines).unwrap();
+        assert_eq!(map.junctions(true).longest_hike(), None);
+        assert_eq!(map.junctions(false).longest_hike(), Some(2));
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Map;
+
+    fn parse(lines: Vec<String>) -> Result<Map> {
+        Map::new(&lines)
+    }
+
+    /// Part 1: Slopes are slippery
+    fn part1(map: &Map) -> Result<Answer> {
+        let length = map
+            .junctions(true)
+            .longest_hike()
+            .context("cannot hike to the end")?;
+        Ok(length.into())
+    }
+
+    /// Part 2: Slopes can be climbed
+    fn part2(map: &Map) -> Result<Answer> {
+        let length = map
+            .junctions(false)
+            .longest_hike()
+            .context("cannot hike to the end")?;
+        Ok(length.into())
+    }
+}
@@ -0,0 +1,257 @@
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use num::{BigInt, BigRational, Zero};
+
+use crate::util::answer::Answer;
+use crate::util::geom::Point3;
+use crate::util::solution::Solution;
+
+/// Where the hailstones have to cross in part 1, on both the x and y axes
+const TEST_AREA: (i128, i128) = (200_000_000_000_000, 400_000_000_000_000);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    position: Point3,
+    velocity: Point3,
+}
+
+impl Hailstone {
+    fn new(input: &str) -> Result<Hailstone> {
+        // Numbers are padded with spaces, so they are not parsed with sscanf
+        let point = |input: &str| {
+            let (x, y, z) = input
+                .split(',')
+                .map(|n| n.trim().parse::<i64>().ok())
+                .collect_tuple()?;
+            Some(Point3::new(x?, y?, z?))
+        };
+        input
+            .split_once('@')
+            .and_then(|(p, v)| {
+                Some(Hailstone { position: point(p)?, velocity: point(v)? })
+            })
+            .with_context(|| format!("invalid hailstone `{}`", input))
+    }
+
+    fn position(&self) -> [i128; 3] {
+        let Point3 { x, y, z } = self.position;
+        [x as i128, y as i128, z as i128]
+    }
+
+    fn velocity(&self) -> [i128; 3] {
+        let Point3 { x, y, z } = self.velocity;
+        [x as i128, y as i128, z as i128]
+    }
+}
+
+/// Whether the paths of two hailstones cross in the future on the x and y axes,
+/// ignoring the z axis, at a point inside the area from low to high.
+/// Parallel paths are said to never cross.
+fn paths_cross(
+    a: &Hailstone,
+    b: &Hailstone,
+    (low, high): (i128, i128),
+) -> bool {
+    let ([ax, ay, _], [avx, avy, _]) = (a.position(), a.velocity());
+    let ([bx, by, _], [bvx, bvy, _]) = (b.position(), b.velocity());
+    // Solves a + t*av = b + s*bv for the times t and s with Cramer's rule,
+    // which are the numerators divided by det
+    let (dx, dy) = (bx - ax, by - ay);
+    let mut det = bvx * avy - avx * bvy;
+    let (mut t, mut s) = (bvx * dy - bvy * dx, avx * dy - avy * dx);
+    if det == 0 {
+        return false;
+    }
+    if det < 0 {
+        (det, t, s) = (-det, -t, -s);
+    }
+    if t < 0 || s < 0 {
+        return false;
+    }
+    // Where they cross, times det
+    let (x, y) = (ax * det + avx * t, ay * det + avy * t);
+    let inside = low * det..=high * det;
+    inside.contains(&x) && inside.contains(&y)
+}
+
+/// Matrix of the linear map from a to a × w
+fn cross_matrix([w0, w1, w2]: [i128; 3]) -> [[i128; 3]; 3] {
+    [[0, w2, -w1], [-w2, 0, w0], [w1, -w0, 0]]
+}
+
+fn cross([a0, a1, a2]: [i128; 3], [b0, b1, b2]: [i128; 3]) -> [i128; 3] {
+    [a1 * b2 - a2 * b1, a2 * b0 - a0 * b2, a0 * b1 - a1 * b0]
+}
+
+fn sub([a0, a1, a2]: [i128; 3], [b0, b1, b2]: [i128; 3]) -> [i128; 3] {
+    [a0 - b0, a1 - b1, a2 - b2]
+}
+
+/// Solves the system of linear equations with Gaussian elimination,
+/// where each row holds the coefficients followed by the constant.
+/// Returns None if there is no single solution.
+fn solve(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|row| !rows[*row][col].is_zero())?;
+        rows.swap(col, pivot);
+        let pivot = rows[col].clone();
+        for (ii, row) in rows.iter_mut().enumerate() {
+            if ii == col || row[col].is_zero() {
+                continue;
+            }
+            let factor = &row[col] / &pivot[col];
+            for (value, p) in row.iter_mut().zip(&pivot) {
+                *value -= &factor * p;
+            }
+        }
+    }
+    Some(rows.iter().enumerate().map(|(ii, row)| &row[n] / &row[ii]).collect())
+}
+
+/// Position and velocity of a rock that hits hailstones a, b, and c,
+/// if there is a single one.
+/// The rock hits hailstone i if (p_i - P) × (v_i - V) = 0,
+/// where P and V are the position and velocity of the rock.
+/// Subtracting this equation for two hailstones i and j leaves
+/// P × (v_i - v_j) + (p_i - p_j) × V = p_i × v_i - p_j × v_j,
+/// which is linear in P and V, so a and b, and a and c, give six equations.
+fn rock(
+    a: &Hailstone,
+    b: &Hailstone,
+    c: &Hailstone,
+) -> Option<[BigRational; 6]> {
+    let mut rows = vec![];
+    for other in [b, c] {
+        let position_matrix = cross_matrix(sub(a.velocity(), other.velocity()));
+        // (p_i - p_j) × V = -(V × (p_i - p_j))
+        let velocity_matrix = cross_matrix(sub(a.position(), other.position()));
+        let constant = sub(
+            cross(a.position(), a.velocity()),
+            cross(other.position(), other.velocity()),
+        );
+        for axis in 0..3 {
+            let row = (position_matrix[axis].iter().copied())
+                .chain(velocity_matrix[axis].iter().map(|v| -v))
+                .chain([constant[axis]])
+                .map(|v| BigRational::from_integer(BigInt::from(v)))
+                .collect();
+            rows.push(row);
+        }
+    }
+    solve(rows)?.try_into().ok()
+}
+
+/// Whether a rock thrown from position at velocity hits the hailstone
+/// at some whole time that is not in the past
+fn hits(position: Point3, velocity: Point3, hailstone: &Hailstone) -> bool {
+    let (dp, dv) =
+        (hailstone.position - position, velocity - hailstone.velocity);
+    let time = [(dp.x, dv.x), (dp.y, dv.y), (dp.z, dv.z)]
+        .into_iter()
+        .find(|(_, v)| *v != 0)
+        .map_or(Some(0), |(p, v)| (p % v == 0).then_some(p / v));
+    time.is_some_and(|t| t >= 0 && dv * t == dp)
+}
+
+/// Position of a rock thrown at whole speeds from a whole position
+/// that hits every hailstone
+fn throw(hailstones: &[Hailstone]) -> Result<Point3> {
+    // The first three hailstones that do not leave a line of rocks
+    let solution = hailstones
+        .iter()
+        .tuple_combinations()
+        .find_map(|(a, b, c)| rock(a, b, c))
+        .context("there are no three hailstones that only one rock hits")?;
+    if solution.iter().any(|v| !v.is_integer()) {
+        bail!("the rock is not thrown from a whole position at whole speeds");
+    }
+    let integer = |v: &BigRational| -> Result<i64> {
+        i64::try_from(v.to_integer()).context("the rock is thrown too far")
+    };
+    let [x, y, z, vx, vy, vz] = solution;
+    let (position, velocity) = (
+        Point3::new(integer(&x)?, integer(&y)?, integer(&z)?),
+        Point3::new(integer(&vx)?, integer(&vy)?, integer(&vz)?),
+    );
+    // Every other hailstone is checked, since only three were used
+    if !hailstones.iter().all(|h| hits(position, velocity, h)) {
+        bail!("the rock misses a hailstone");
+    }
+    Ok(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hailstones() -> Vec<Hailstone> {
+        [
+            "19, 13, 30 @ -2,  1, -2",
+            "18, 19, 22 @ -1, -1, -2",
+            "20, 25, 34 @ -2, -2, -4",
+            "12, 31, 28 @ -1, -2, -1",
+            "20, 19, 15 @  1, -5, -3",
+        ]
+        .iter()
+        .map(|s| Hailstone::new(s).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_paths_cross() {
+        let hailstones = hailstones();
+        let crossings = hailstones
+            .iter()
+            .tuple_combinations()
+            .filter(|(a, b)| paths_cross(a, b, (7, 27)))
+            .count();
+        assert_eq!(crossings, 2);
+        // Parallel paths
+        assert!(!paths_cross(&hailstones[1], &hailstones[2], (7, 27)));
+        // Crossed in the past for the first hailstone
+        assert!(!paths_cross(&hailstones[0], &hailstones[4], (-100, 100)));
+    }
+
+    #[test]
+    fn test_throw() {
+        let hailstones = hailstones();
+        assert_eq!(throw(&hailstones).unwrap(), Point3::new(24, 13, 10));
+        assert!(hits(
+            Point3::new(24, 13, 10),
+            Point3::new(-3, 1, 2),
+            &hailstones[0]
+        ));
+        assert!(!hits(
+            Point3::new(24, 13, 10),
+            Point3::new(-3, 1, 1),
+            &hailstones[0]
+        ));
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<Hailstone>> {
+        lines.iter().map(|line| Hailstone::new(line)).collect()
+    }
+
+    /// Part 1: Count pairs of hailstones whose paths cross inside the test area
+    fn part1(hailstones: &Vec<Hailstone>) -> Result<Answer> {
+        let crossings = hailstones
+            .iter()
+            .tuple_combinations()
+            .filter(|(a, b)| paths_cross(a, b, TEST_AREA))
+            .count();
+        Ok(crossings.into())
+    }
+
+    /// Part 2: Sum the coordinates of where to throw a rock to hit every hailstone
+    fn part2(hailstones: &Vec<Hailstone>) -> Result<Answer> {
+        let position = throw(hailstones)?;
+        Ok((position.x + position.y + position.z).into())
+    }
+}
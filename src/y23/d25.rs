@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use priority_queue::PriorityQueue;
+
+use crate::util::answer::Answer;
+use crate::util::solution::Solution;
+
+/// Wires that have to be cut to split the components into two groups
+const WIRES_TO_CUT: usize = 3;
+
+/// Components, and how many wires connect each pair of them
+pub struct Wiring {
+    wires: Vec<HashMap<usize, usize>>,
+}
+
+impl Wiring {
+    fn new(lines: &[String]) -> Result<Wiring> {
+        let mut indices = HashMap::new();
+        let mut wires: Vec<HashMap<usize, usize>> = vec![];
+        let mut index = |name: &str| {
+            *indices.entry(name.to_string()).or_insert_with(|| {
+                wires.push(HashMap::new());
+                wires.len() - 1
+            })
+        };
+        let mut connections = vec![];
+        for line in lines {
+            let (component, others) = line
+                .split_once(": ")
+                .with_context(|| format!("invalid connections `{}`", line))?;
+            let component = index(component);
+            for other in others.split_whitespace() {
+                connections.push((component, index(other)));
+            }
+        }
+        for (a, b) in connections {
+            if a == b {
+                bail!("a component is connected to itself");
+            }
+            *wires[a].entry(b).or_default() += 1;
+            *wires[b].entry(a).or_default() += 1;
+        }
+        Ok(Wiring { wires })
+    }
+
+    /// Fewest wires to cut to split the components into two groups,
+    /// and how many components are in one of the groups.
+    /// Uses the Stoer-Wagner algorithm, which merges two components every phase.
+    fn minimum_cut(&self) -> Option<(usize, usize)> {
+        let mut wires = self.wires.clone();
+        // How many components each one has been merged from,
+        // where merged components are left with no wires
+        let mut sizes = vec![1; wires.len()];
+        let mut merged = vec![false; wires.len()];
+        let mut best: Option<(usize, usize)> = None;
+        for _ in 1..wires.len() {
+            // Adds the component most tightly connected to those added so far,
+            // until the last two remain
+            let mut queue = (0..wires.len())
+                .filter(|c| !merged[*c])
+                .map(|c| (c, 0))
+                .collect::<PriorityQueue<_, _>>();
+            let (mut s, mut t, mut cut) = (None, None, 0);
+            while let Some((component, connection)) = queue.pop() {
+                (s, t, cut) = (t, Some(component), connection);
+                for (&other, &count) in &wires[component] {
+                    queue.change_priority_by(&other, |c| *c += count);
+                }
+            }
+            let (s, t) = (s?, t?);
+            if best.is_none_or(|(fewest, _)| cut < fewest) {
+                best = Some((cut, sizes[t]));
+            }
+            // Merges t into s
+            for (other, count) in std::mem::take(&mut wires[t]) {
+                wires[other].remove(&t);
+                if other != s {
+                    *wires[s].entry(other).or_default() += count;
+                    *wires[other].entry(s).or_default() += count;
+                }
+            }
+            sizes[s] += sizes[t];
+            merged[t] = true;
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wiring(lines: &[&str]) -> Wiring {
+        let lines = lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        Wiring::new(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_minimum_cut() {
+        // Two triangles connected by one wire
+        let triangles = wiring(&["a: b c", "b: c", "d: e f", "e: f", "c: d"]);
+        assert_eq!(triangles.minimum_cut(), Some((1, 3)));
+        // A square, which is split into two pairs or one and three
+        let square = wiring(&["a: b", "b: c", "c: d", "d: a"]);
+        assert_eq!(square.minimum_cut().map(|(cut, _)| cut), Some(2));
+        // Two pairs that are not connected at all
+        let lonely = wiring(&["a: b", "c: d"]);
+        assert_eq!(lonely.minimum_cut().map(|(cut, _)| cut), Some(0));
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Wiring;
+
+    fn parse(lines: Vec<String>) -> Result<Wiring> {
+        Wiring::new(&lines)
+    }
+
+    /// Part 1: Multiply the sizes of the two groups left after cutting three wires
+    fn part1(wiring: &Wiring) -> Result<Answer> {
+        let (cut, size) = wiring
+            .minimum_cut()
+            .context("there are too few components to split")?;
+        if cut != WIRES_TO_CUT {
+            bail!("expected to cut {} wires, not {}", WIRES_TO_CUT, cut);
+        }
+        Ok((size * (wiring.wires.len() - size)).into())
+    }
+
+    /// Part 2: There is no part 2
+    fn part2(_: &Wiring) -> Result<Answer> {
+        Ok("Merry Christmas!".into())
+    }
+}
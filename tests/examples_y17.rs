@@ -133,6 +133,108 @@ Generator B starts with 8921",
             "309",
         ),
         // puzzle(16, "s1,x3/4,pe/b", "baedc", "ceadb"), // TODO: Unit test
+        puzzle(
+            18,
+            "set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2",
+            "4",
+            "",
+        ),
+        puzzle(
+            18,
+            "snd 1
+snd 2
+snd p
+rcv a
+rcv b
+rcv c
+rcv d",
+            "",
+            "3",
+        ),
+        puzzle(
+            19,
+            "     |
+     |  +--+
+     A  |  C
+ F---|----E|--+
+     |  |  |  D
+     +B-+  +--+",
+            "ABCDEF",
+            "38",
+        ),
+        puzzle(
+            20,
+            "p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>
+p=< 4,0,0>, v=< 0,0,0>, a=<-2,0,0>",
+            "0",
+            "",
+        ),
+        puzzle(
+            20,
+            "p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>
+p=<-4,0,0>, v=< 2,0,0>, a=< 0,0,0>
+p=<-2,0,0>, v=< 1,0,0>, a=< 0,0,0>
+p=< 3,0,0>, v=<-1,0,0>, a=< 0,0,0>",
+            "",
+            "1",
+        ),
+        puzzle(
+            22,
+            "..#
+#..
+...",
+            "5587",
+            "2511944",
+        ),
+        puzzle(
+            24,
+            "0/2
+2/2
+2/3
+3/4
+3/5
+0/1
+10/1
+9/10",
+            "31",
+            "19",
+        ),
+        puzzle(
+            25,
+            "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.",
+            "3",
+            "",
+        ),
     ]
 }
 
@@ -0,0 +1,655 @@
+use aoc_rs::util::puzzles::puzzle_generator;
+use aoc_rs::util::puzzles::Puzzle;
+use aoc_rs::util::test_puzzle;
+
+pub fn examples() -> Vec<Puzzle> {
+    let puzzle = puzzle_generator(22);
+    vec![
+        puzzle(
+            1,
+            "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000",
+            "24000",
+            "45000",
+        ),
+        puzzle(
+            2,
+            "A Y
+B X
+C Z",
+            "15",
+            "12",
+        ),
+        puzzle(
+            3,
+            "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw",
+            "157",
+            "70",
+        ),
+        puzzle(
+            4,
+            "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8",
+            "2",
+            "4",
+        ),
+        puzzle(
+            5,
+            "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2",
+            "CMZ",
+            "MCD",
+        ),
+        puzzle(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", "7", "19"),
+        puzzle(6, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", "11", "26"),
+        puzzle(
+            7,
+            "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k",
+            "95437",
+            "24933642",
+        ),
+        puzzle(
+            8,
+            "30373
+25512
+65332
+33549
+35390",
+            "21",
+            "8",
+        ),
+        puzzle(
+            9,
+            "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2",
+            "13",
+            "1",
+        ),
+        puzzle(
+            9,
+            "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20",
+            "",
+            "36",
+        ),
+        // Its picture is not made of letters, so part 2 is not checked
+        puzzle(
+            10,
+            "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop",
+            "13140",
+            "",
+        ),
+        // Not the puzzle's example, but a program that draws letters like a puzzle input would
+        puzzle(
+            10,
+            "addx 1
+addx 4
+noop
+noop
+addx 1
+addx 3
+addx 1
+addx 5
+noop
+noop
+addx 22
+addx -16
+addx -1
+addx -17
+addx 24
+addx -1
+addx 5
+addx -1
+addx -17
+addx 22
+addx -18
+addx 2
+addx -16
+addx 2
+addx 20
+addx 2
+addx -19
+addx 5
+addx 2
+addx 20
+addx -19
+addx 2
+addx 5
+addx -20
+addx 2
+addx 21
+addx 2
+addx 5
+addx 2
+addx -20
+addx 21
+addx -17
+addx -19
+addx 5
+noop
+noop
+addx 22
+addx -18
+addx 1
+addx 5
+addx 20
+addx -19
+addx 2
+addx 5
+addx -20
+addx 2
+addx 21
+addx 2
+addx 5
+addx 2
+addx -20
+addx 2
+addx -16
+addx 20
+addx -16
+addx 20
+addx 2
+addx -19
+addx 5
+addx 2
+noop
+noop
+addx 22
+addx -18
+addx 2
+addx -18
+addx 2
+addx 21
+addx 2
+addx 5
+addx 2
+addx 2
+addx -1
+addx -38
+addx 23
+addx -16
+addx 20
+addx 2
+addx -19
+addx 5
+addx 2
+addx 2
+addx 20
+addx -19
+addx 5
+addx 2
+addx -20
+addx 21
+addx 2
+addx 5
+addx 2
+addx -20
+addx 21
+addx -37
+addx 2
+addx 4
+noop
+noop
+addx 1
+addx 3
+addx 1
+addx 5
+addx 20
+addx -19
+addx 2
+addx 5
+addx -20
+addx 22
+addx -18
+addx 24
+addx -1
+addx -17
+addx 22
+addx 1
+noop
+noop",
+            "",
+            "ZEBRAJOG",
+        ),
+        puzzle(
+            11,
+            "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1",
+            "10605",
+            "2713310158",
+        ),
+        puzzle(
+            12,
+            "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi",
+            "31",
+            "29",
+        ),
+        puzzle(
+            13,
+            "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]",
+            "13",
+            "140",
+        ),
+        puzzle(
+            14,
+            "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9",
+            "24",
+            "93",
+        ),
+        // Day 15's example checks a different row and area, so it is in src/y22/d15.rs (test_example)
+        puzzle(
+            16,
+            "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II",
+            "1651",
+            "1707",
+        ),
+        puzzle(
+            17,
+            ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>",
+            "3068",
+            "1514285714288",
+        ),
+        puzzle(
+            18,
+            "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5",
+            "64",
+            "58",
+        ),
+        puzzle(
+            19,
+            "Blueprint 1:
+  Each ore robot costs 4 ore.
+  Each clay robot costs 2 ore.
+  Each obsidian robot costs 3 ore and 14 clay.
+  Each geode robot costs 2 ore and 7 obsidian.
+
+Blueprint 2:
+  Each ore robot costs 2 ore.
+  Each clay robot costs 3 ore.
+  Each obsidian robot costs 3 ore and 8 clay.
+  Each geode robot costs 3 ore and 12 obsidian.",
+            "33",
+            "3472",
+        ),
+        puzzle(
+            20,
+            "1
+2
+-3
+3
+-2
+0
+4",
+            "3",
+            "1623178306",
+        ),
+        puzzle(
+            21,
+            "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32",
+            "152",
+            "301",
+        ),
+        puzzle(
+            22,
+            "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5",
+            "6032",
+            "5031",
+        ),
+        puzzle(
+            23,
+            "....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..",
+            "110",
+            "20",
+        ),
+        puzzle(
+            24,
+            "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+            "18",
+            "54",
+        ),
+        puzzle(
+            25,
+            "1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122",
+            "2=-1=0",
+            "",
+        ),
+    ]
+}
+
+#[test]
+fn test_examples() {
+    let puzzles = examples();
+    for puzzle in &puzzles {
+        test_puzzle(puzzle);
+    }
+}
@@ -0,0 +1,45 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Creates an empty folder for a test to write its files into
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "aoc_rs-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::create_dir_all(dir.join("outputs")).unwrap();
+    dir
+}
+
+#[test]
+fn test_run_with_truncated_output() {
+    let dir = scratch_dir("truncated-output");
+    fs::write(dir.join("inputs/y21-d01.txt"), "199\n200\n208\n").unwrap();
+    fs::write(dir.join("inputs/y21-d02.txt"), "down 5\nforward 5\n").unwrap();
+    // Has only one line, which is too short to hold both answers
+    fs::write(dir.join("outputs/y21-d01.txt"), "2").unwrap();
+    fs::write(dir.join("outputs/y21-d02.txt"), "25\n125\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc_rs"))
+        .args(["run", "--year", "21", "--inputs-dir"])
+        .arg(dir.join("inputs"))
+        .arg("--outputs-dir")
+        .arg(dir.join("outputs"))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(!output.status.success());
+    let line = |name: &str| {
+        stdout.lines().find(|line| line.starts_with(name)).unwrap_or("")
+    };
+    assert!(line("y21-d01").contains("too short"), "{}", stdout);
+    // The puzzles after it still run
+    assert!(line("y21-d02").contains("pass"), "{}", stdout);
+    assert!(stdout.contains("2 passed, 2 failed"), "{}", stdout);
+}